@@ -0,0 +1,233 @@
+//! Type-safe angle units.
+
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use {Angle, Float};
+
+/// An angle in radians.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Rad<T>(pub T);
+
+/// An angle in degrees.
+#[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+pub struct Deg<T>(pub T);
+
+impl<T: Float> Rad<T> {
+    /// Returns the zero angle.
+    #[inline(always)]
+    pub fn zero() -> Rad<T> { Rad(T::zero()) }
+
+    /// Returns a full turn (2π).
+    #[inline(always)]
+    pub fn full_turn() -> Rad<T> { Rad(T::_360()) }
+
+    /// Returns a half turn (π).
+    #[inline(always)]
+    pub fn half_turn() -> Rad<T> { Rad(T::_180()) }
+
+    /// Returns a quarter turn (π/2).
+    #[inline(always)]
+    pub fn quarter_turn() -> Rad<T> { Rad(T::_90()) }
+
+    /// Wraps the angle into the range `[0, 2π)`.
+    #[inline(always)]
+    pub fn normalize(self) -> Rad<T> { Rad(self.0.wrap_to_tau()) }
+
+    /// Wraps the angle into the range `[-π, π)`.
+    #[inline(always)]
+    pub fn normalize_signed(self) -> Rad<T> { Rad(self.0.wrap_to_pi()) }
+
+    /// Returns the sine.
+    #[inline(always)]
+    pub fn sin(self) -> T { self.0.sin() }
+
+    /// Returns the cosine.
+    #[inline(always)]
+    pub fn cos(self) -> T { self.0.cos() }
+
+    /// Returns the tangent.
+    #[inline(always)]
+    pub fn tan(self) -> T { self.0.tan() }
+
+    /// Returns the angle whose sine is `x`.
+    #[inline(always)]
+    pub fn asin(x: T) -> Rad<T> { Rad(x.asin()) }
+
+    /// Returns the angle whose cosine is `x`.
+    #[inline(always)]
+    pub fn acos(x: T) -> Rad<T> { Rad(x.acos()) }
+
+    /// Returns the angle whose tangent is `x`.
+    #[inline(always)]
+    pub fn atan(x: T) -> Rad<T> { Rad(x.atan()) }
+
+    /// Returns the four quadrant angle of the point `(x, y)`.
+    #[inline(always)]
+    pub fn atan2(y: T, x: T) -> Rad<T> { Rad(y.atan2(x)) }
+}
+
+impl<T: Float> Deg<T> {
+    /// Returns the zero angle.
+    #[inline(always)]
+    pub fn zero() -> Deg<T> { Deg(T::zero()) }
+
+    /// Returns a full turn (360°).
+    #[inline(always)]
+    pub fn full_turn() -> Deg<T> { Deg(T::from_f64(360.0)) }
+
+    /// Returns a half turn (180°).
+    #[inline(always)]
+    pub fn half_turn() -> Deg<T> { Deg(T::from_f64(180.0)) }
+
+    /// Returns a quarter turn (90°).
+    #[inline(always)]
+    pub fn quarter_turn() -> Deg<T> { Deg(T::from_f64(90.0)) }
+
+    /// Wraps the angle into the range `[0°, 360°)`.
+    #[inline(always)]
+    pub fn normalize(self) -> Deg<T> {
+        let full = Self::full_turn().0;
+        let mut r = self.0 % full;
+        if r < T::zero() { r += full; }
+        Deg(if r >= full { T::zero() } else { r })
+    }
+
+    /// Wraps the angle into the range `[-180°, 180°)`.
+    #[inline(always)]
+    pub fn normalize_signed(self) -> Deg<T> {
+        let (full, half) = (Self::full_turn().0, Self::half_turn().0);
+        let r = self.0 % full;
+        Deg(if r >= half {
+            r - full
+        } else if r < -half {
+            r + full
+        } else {
+            r
+        })
+    }
+}
+
+impl<T: Float> From<Deg<T>> for Rad<T> {
+    #[inline(always)]
+    fn from(deg: Deg<T>) -> Rad<T> { Rad(deg.0.deg_to_rad()) }
+}
+
+impl<T: Float> From<Rad<T>> for Deg<T> {
+    #[inline(always)]
+    fn from(rad: Rad<T>) -> Deg<T> { Deg(rad.0.rad_to_deg()) }
+}
+
+macro_rules! angle_ops {
+    ($($unit:ident),*) => {
+        $(
+            impl<T: Float> Add for $unit<T> {
+                type Output = $unit<T>;
+                #[inline(always)]
+                fn add(self, other: $unit<T>) -> $unit<T> { $unit(self.0 + other.0) }
+            }
+
+            impl<T: Float> Sub for $unit<T> {
+                type Output = $unit<T>;
+                #[inline(always)]
+                fn sub(self, other: $unit<T>) -> $unit<T> { $unit(self.0 - other.0) }
+            }
+
+            impl<T: Float> Neg for $unit<T> {
+                type Output = $unit<T>;
+                #[inline(always)]
+                fn neg(self) -> $unit<T> { $unit(-self.0) }
+            }
+
+            impl<T: Float> Mul<T> for $unit<T> {
+                type Output = $unit<T>;
+                #[inline(always)]
+                fn mul(self, scale: T) -> $unit<T> { $unit(self.0 * scale) }
+            }
+
+            impl<T: Float> Div<T> for $unit<T> {
+                type Output = $unit<T>;
+                #[inline(always)]
+                fn div(self, scale: T) -> $unit<T> { $unit(self.0 / scale) }
+            }
+
+            impl<T: Float> Div for $unit<T> {
+                type Output = T;
+                #[inline(always)]
+                fn div(self, other: $unit<T>) -> T { self.0 / other.0 }
+            }
+
+            impl<T: Float> AddAssign for $unit<T> {
+                #[inline(always)]
+                fn add_assign(&mut self, other: $unit<T>) { self.0 += other.0 }
+            }
+
+            impl<T: Float> SubAssign for $unit<T> {
+                #[inline(always)]
+                fn sub_assign(&mut self, other: $unit<T>) { self.0 -= other.0 }
+            }
+
+            impl<T: Float> MulAssign<T> for $unit<T> {
+                #[inline(always)]
+                fn mul_assign(&mut self, scale: T) { self.0 *= scale }
+            }
+
+            impl<T: Float> DivAssign<T> for $unit<T> {
+                #[inline(always)]
+                fn div_assign(&mut self, scale: T) { self.0 /= scale }
+            }
+        )*
+    };
+}
+
+angle_ops!(Rad, Deg);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ApproxEq;
+
+    #[test]
+    fn test_conversions() {
+        let r: Rad<f64> = Deg(180.0).into();
+        assert_eq!(r, Rad::half_turn());
+        let d: Deg<f32> = Rad::quarter_turn().into();
+        assert!(d.0.approx_eq(90.0, 1e-5));
+        assert_eq!(Rad::from(Deg::<f64>::full_turn()), Rad::full_turn());
+    }
+
+    #[test]
+    fn test_ops() {
+        let a = Rad(1.0_f64) + Rad(0.5) - Rad(0.25);
+        assert_eq!(a, Rad(1.25));
+        assert_eq!(-a * 2.0, Rad(-2.5));
+        assert_eq!(a / 5.0, Rad(0.25));
+        assert_eq!(Deg(90.0_f32) / Deg(45.0), 2.0);
+        let mut b = Deg(10.0_f64);
+        b += Deg(5.0);
+        b *= 2.0;
+        b -= Deg(10.0);
+        b /= 4.0;
+        assert_eq!(b, Deg(5.0));
+    }
+
+    #[test]
+    fn test_trig() {
+        assert_eq!(Rad(0.0_f64).sin(), 0.0);
+        assert_eq!(Rad(0.0_f32).cos(), 1.0);
+        assert!(Rad::<f64>::from(Deg(45.0)).tan().approx_eq(1.0, 1e-15));
+        assert_eq!(Rad::atan2(1.0_f64, 0.0), Rad::quarter_turn());
+        assert_eq!(Rad::asin(1.0_f64), Rad::quarter_turn());
+        assert_eq!(Rad::acos(1.0_f64), Rad::zero());
+        assert_eq!(Rad::atan(0.0_f64), Rad::zero());
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(Deg(370.0_f64).normalize(), Deg(10.0));
+        assert_eq!(Deg(-10.0_f64).normalize(), Deg(350.0));
+        assert_eq!(Deg(180.0_f64).normalize_signed(), Deg(-180.0));
+        assert_eq!(Deg(-190.0_f64).normalize_signed(), Deg(170.0));
+        assert_eq!((Rad::half_turn() * 3.0_f64).normalize(), Rad::half_turn());
+        assert_eq!(Rad(0.5_f64).normalize_signed(), Rad(0.5));
+    }
+}
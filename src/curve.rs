@@ -0,0 +1,87 @@
+//! Polynomial and spline evaluation.
+//!
+//! The splines interpolate scalars; evaluate them per component for points.
+
+use {Float, Lerp};
+
+/// Evaluates a polynomial at `x` with Horner's scheme.
+///
+/// The coefficients are ordered from the highest degree down to the constant term,
+/// so `[a, b, c]` is `a * x^2 + b * x + c`. Returns zero for no coefficients.
+pub fn polyval<T: Float>(coeffs: &[T], x: T) -> T {
+    coeffs.iter().fold(T::zero(), |acc, &c| acc * x + c)
+}
+
+/// Evaluates the cubic Hermite spline from `p0` with tangent `m0` to `p1` with tangent `m1`.
+///
+/// Returns `p0` at `t = 0` and `p1` at `t = 1`.
+pub fn cubic_hermite<T: Float>(p0: T, m0: T, p1: T, m1: T, t: T) -> T {
+    let (two, three) = (T::from_f64(2.0), T::from_f64(3.0));
+    let (t2, t3) = (t * t, t * t * t);
+    let h00 = two * t3 - three * t2 + T::one();
+    let h10 = t3 - two * t2 + t;
+    let h01 = three * t2 - two * t3;
+    let h11 = t3 - t2;
+    h00 * p0 + h10 * m0 + h01 * p1 + h11 * m1
+}
+
+/// Evaluates the uniform Catmull-Rom spline through four control points
+/// between `p1` at `t = 0` and `p2` at `t = 1`.
+///
+/// Chaining segments over consecutive points gives a curve with a continuous tangent.
+pub fn catmull_rom<T: Float>(p0: T, p1: T, p2: T, p3: T, t: T) -> T {
+    let half = T::from_f64(0.5);
+    cubic_hermite(p1, (p2 - p0) * half, p2, (p3 - p1) * half, t)
+}
+
+/// Evaluates the cubic Bézier curve with control points `p0` to `p3`.
+pub fn cubic_bezier<T: Float>(p0: T, p1: T, p2: T, p3: T, t: T) -> T {
+    // De Casteljau's algorithm, which stays within the hull of the control points.
+    let a = p0.lerp(p1, t);
+    let b = p1.lerp(p2, t);
+    let c = p2.lerp(p3, t);
+    a.lerp(b, t).lerp(b.lerp(c, t), t)
+}
+
+/// Returns the derivative with respect to `t` of `cubic_bezier`.
+pub fn cubic_bezier_derivative<T: Float>(p0: T, p1: T, p2: T, p3: T, t: T) -> T {
+    let three = T::from_f64(3.0);
+    let (a, b, c) = (p1 - p0, p2 - p1, p3 - p2);
+    three * a.lerp(b, t).lerp(b.lerp(c, t), t)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_polyval() {
+        assert_eq!(polyval(&[2.0_f64, -3.0, 1.0], 4.0), 21.0);
+        assert_eq!(polyval(&[5.0_f32], 100.0), 5.0);
+        assert_eq!(polyval::<f32>(&[], 1.0), 0.0);
+    }
+
+    #[test]
+    fn test_hermite() {
+        assert_eq!(cubic_hermite(1.0_f64, 5.0, 3.0, -2.0, 0.0), 1.0);
+        assert_eq!(cubic_hermite(1.0_f64, 5.0, 3.0, -2.0, 1.0), 3.0);
+        // Matching tangents reproduce a straight line.
+        assert_eq!(cubic_hermite(0.0_f64, 2.0, 2.0, 2.0, 0.25), 0.5);
+        assert_eq!(catmull_rom(0.0_f64, 1.0, 2.0, 3.0, 0.5), 1.5);
+        assert_eq!(catmull_rom(0.0_f32, 1.0, 4.0, 9.0, 1.0), 4.0);
+    }
+
+    #[test]
+    fn test_bezier() {
+        assert_eq!(cubic_bezier(0.0_f64, 1.0, 3.0, 4.0, 0.0), 0.0);
+        assert_eq!(cubic_bezier(0.0_f64, 1.0, 3.0, 4.0, 1.0), 4.0);
+        assert_eq!(cubic_bezier(0.0_f64, 1.0, 3.0, 4.0, 0.5), 2.0);
+        assert_eq!(cubic_bezier_derivative(0.0_f64, 1.0, 3.0, 4.0, 0.0), 3.0);
+        assert_eq!(cubic_bezier_derivative(0.0_f64, 1.0, 3.0, 4.0, 1.0), 3.0);
+        let h = 1e-6;
+        let (p, t) = ([0.5_f64, -2.0, 7.0, 1.0], 0.3);
+        let numeric = (cubic_bezier(p[0], p[1], p[2], p[3], t + h)
+            - cubic_bezier(p[0], p[1], p[2], p[3], t - h)) / (2.0 * h);
+        assert!((cubic_bezier_derivative(p[0], p[1], p[2], p[3], t) - numeric).abs() < 1e-6);
+    }
+}
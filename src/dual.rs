@@ -0,0 +1,616 @@
+//! Dual numbers for forward-mode automatic differentiation.
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::num::FpCategory;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
+
+use {
+    Abs, ApproxEq, Cbrt, Clamp, Classify, ConstOne, ConstRadians, ConstZero, Consts, EuclidOps, Exp,
+    Float, FormatFloat, FromBytes, FromPrimitive, Log, Max, Midpoint, Min, MulAdd, One, ParseFloat,
+    ParseFloatError, Powf, Powi, Radians, Recip, Round, Signum, Sqrt, ToBytes, ToPrimitive,
+    TotalOrder, Trig, Zero,
+};
+
+/// A value paired with its derivative.
+///
+/// Every operation applies the chain rule, so evaluating generic `Float` code
+/// on `Dual::variable(x)` yields both `f(x)` and the exact derivative `f'(x)`.
+///
+/// Comparisons, classification and conversions only look at the value.
+/// Piecewise constant functions such as `floor` or `signum` have zero derivative.
+///
+/// ```
+/// use float::{Dual, Float};
+///
+/// fn f<T: Float>(x: T) -> T { x * x.sin() }
+///
+/// let y = f(Dual::variable(2.0_f64));
+/// assert_eq!(y.value, 2.0 * 2.0_f64.sin());
+/// assert_eq!(y.deriv, 2.0_f64.sin() + 2.0 * 2.0_f64.cos());
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Dual<T> {
+    /// The value.
+    pub value: T,
+    /// The derivative with respect to the input variable.
+    pub deriv: T,
+}
+
+impl<T: Float> Dual<T> {
+    /// Returns a dual number from a value and its derivative.
+    #[inline(always)]
+    pub fn new(value: T, deriv: T) -> Dual<T> { Dual { value, deriv } }
+
+    /// Returns a constant, with zero derivative.
+    #[inline(always)]
+    pub fn constant(value: T) -> Dual<T> { Dual::new(value, T::zero()) }
+
+    /// Returns the input variable, with unit derivative.
+    #[inline(always)]
+    pub fn variable(value: T) -> Dual<T> { Dual::new(value, T::one()) }
+
+    /// Applies a function with value `f` and derivative `df` at `self.value`.
+    #[inline(always)]
+    fn chain(self, f: T, df: T) -> Dual<T> { Dual::new(f, self.deriv * df) }
+}
+
+impl<T: Float> PartialEq for Dual<T> {
+    #[inline(always)]
+    fn eq(&self, other: &Dual<T>) -> bool { self.value == other.value }
+}
+
+impl<T: Float> PartialOrd for Dual<T> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Dual<T>) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<T: Float> Add for Dual<T> {
+    type Output = Dual<T>;
+    #[inline(always)]
+    fn add(self, other: Dual<T>) -> Dual<T> {
+        Dual::new(self.value + other.value, self.deriv + other.deriv)
+    }
+}
+
+impl<T: Float> Sub for Dual<T> {
+    type Output = Dual<T>;
+    #[inline(always)]
+    fn sub(self, other: Dual<T>) -> Dual<T> {
+        Dual::new(self.value - other.value, self.deriv - other.deriv)
+    }
+}
+
+impl<T: Float> Mul for Dual<T> {
+    type Output = Dual<T>;
+    #[inline(always)]
+    fn mul(self, other: Dual<T>) -> Dual<T> {
+        Dual::new(self.value * other.value,
+                  self.deriv * other.value + self.value * other.deriv)
+    }
+}
+
+impl<T: Float> Div for Dual<T> {
+    type Output = Dual<T>;
+    #[inline(always)]
+    fn div(self, other: Dual<T>) -> Dual<T> {
+        let value = self.value / other.value;
+        Dual::new(value, (self.deriv - value * other.deriv) / other.value)
+    }
+}
+
+impl<T: Float> Rem for Dual<T> {
+    type Output = Dual<T>;
+    #[inline(always)]
+    fn rem(self, other: Dual<T>) -> Dual<T> {
+        let q = (self.value / other.value).trunc();
+        Dual::new(self.value % other.value, self.deriv - other.deriv * q)
+    }
+}
+
+impl<T: Float> Neg for Dual<T> {
+    type Output = Dual<T>;
+    #[inline(always)]
+    fn neg(self) -> Dual<T> { Dual::new(-self.value, -self.deriv) }
+}
+
+macro_rules! assign_impl {
+    ($($op_assign:ident::$f_assign:ident => $f:ident),*) => {
+        $(
+            impl<T: Float> $op_assign for Dual<T> {
+                #[inline(always)]
+                fn $f_assign(&mut self, other: Dual<T>) { *self = self.$f(other) }
+            }
+        )*
+    };
+}
+
+assign_impl!(AddAssign::add_assign => add, SubAssign::sub_assign => sub,
+             MulAssign::mul_assign => mul, DivAssign::div_assign => div,
+             RemAssign::rem_assign => rem);
+
+impl<T: Float> Min for Dual<T> {
+    #[inline(always)]
+    fn min(self, other: Dual<T>) -> Dual<T> {
+        if other.value < self.value || self.value.is_nan() { other } else { self }
+    }
+}
+
+impl<T: Float> Max for Dual<T> {
+    #[inline(always)]
+    fn max(self, other: Dual<T>) -> Dual<T> {
+        if other.value > self.value || self.value.is_nan() { other } else { self }
+    }
+}
+
+impl<T: Float> Midpoint for Dual<T> {
+    #[inline(always)]
+    fn midpoint(self, other: Dual<T>) -> Dual<T> {
+        Dual::new(self.value.midpoint(other.value), self.deriv.midpoint(other.deriv))
+    }
+}
+
+impl<T: Float> Clamp for Dual<T> {
+    #[inline(always)]
+    fn clamp(self, min: Dual<T>, max: Dual<T>) -> Dual<T> {
+        assert!(min.value <= max.value, "min > max, or either was NaN");
+        if self.value < min.value { min } else if self.value > max.value { max } else { self }
+    }
+
+    #[inline(always)]
+    fn clamp01(self) -> Dual<T> { self.clamp(Dual::zero(), Dual::one()) }
+}
+
+impl<T: Float> Signum for Dual<T> {
+    #[inline(always)]
+    fn signum(self) -> Dual<T> { Dual::constant(self.value.signum()) }
+}
+
+impl<T: Float> Abs for Dual<T> {
+    #[inline(always)]
+    fn abs(self) -> Dual<T> { self.chain(self.value.abs(), T::one().copysign(self.value)) }
+
+    #[inline(always)]
+    fn copysign(self, sign: Dual<T>) -> Dual<T> {
+        let flip = T::one().copysign(self.value) * T::one().copysign(sign.value);
+        self.chain(self.value.copysign(sign.value), flip)
+    }
+
+    #[inline(always)]
+    fn hypot(self, other: Dual<T>) -> Dual<T> {
+        let h = self.value.hypot(other.value);
+        Dual::new(h, (self.value * self.deriv + other.value * other.deriv) / h)
+    }
+}
+
+impl<T: Float> Powf for Dual<T> {
+    #[inline(always)]
+    fn powf(self, other: Dual<T>) -> Dual<T> {
+        let value = self.value.powf(other.value);
+        let mut deriv = other.value * self.value.powf(other.value - T::one()) * self.deriv;
+        // Skipped for constant exponents, since `ln` is NaN for negative bases.
+        if other.deriv != T::zero() {
+            deriv += value * self.value.ln() * other.deriv;
+        }
+        Dual::new(value, deriv)
+    }
+}
+
+impl<T: Float> Powi for Dual<T> {
+    #[inline(always)]
+    fn powi(self, n: i32) -> Dual<T> {
+        self.chain(self.value.powi(n), T::from_i32(n) * self.value.powi(n - 1))
+    }
+
+    #[inline(always)]
+    fn sqr(self) -> Dual<T> { self * self }
+
+    #[inline(always)]
+    fn cube(self) -> Dual<T> { self * self * self }
+}
+
+impl<T: Float> MulAdd for Dual<T> {
+    #[inline(always)]
+    fn mul_add(self, a: Dual<T>, b: Dual<T>) -> Dual<T> {
+        Dual::new(self.value.mul_add(a.value, b.value),
+                  self.deriv.mul_add(a.value, self.value.mul_add(a.deriv, b.deriv)))
+    }
+}
+
+impl<T: Float> Exp for Dual<T> {
+    #[inline(always)]
+    fn exp(self) -> Dual<T> {
+        let e = self.value.exp();
+        self.chain(e, e)
+    }
+
+    #[inline(always)]
+    fn exp2(self) -> Dual<T> {
+        let e = self.value.exp2();
+        self.chain(e, e * T::from_f64(2.0).ln())
+    }
+
+    #[inline(always)]
+    fn exp_m1(self) -> Dual<T> { self.chain(self.value.exp_m1(), self.value.exp()) }
+}
+
+impl<T: Float> Log for Dual<T> {
+    #[inline(always)]
+    fn ln(self) -> Dual<T> { self.chain(self.value.ln(), self.value.recip()) }
+
+    #[inline(always)]
+    fn log(self, base: Dual<T>) -> Dual<T> { self.ln() / base.ln() }
+
+    #[inline(always)]
+    fn log2(self) -> Dual<T> {
+        self.chain(self.value.log2(), (self.value * T::from_f64(2.0).ln()).recip())
+    }
+
+    #[inline(always)]
+    fn log10(self) -> Dual<T> {
+        self.chain(self.value.log10(), (self.value * T::from_f64(10.0).ln()).recip())
+    }
+
+    #[inline(always)]
+    fn ln_1p(self) -> Dual<T> {
+        self.chain(self.value.ln_1p(), (T::one() + self.value).recip())
+    }
+}
+
+impl<T: Float> Round for Dual<T> {
+    #[inline(always)]
+    fn floor(self) -> Dual<T> { Dual::constant(self.value.floor()) }
+
+    #[inline(always)]
+    fn ceil(self) -> Dual<T> { Dual::constant(self.value.ceil()) }
+
+    #[inline(always)]
+    fn round(self) -> Dual<T> { Dual::constant(self.value.round()) }
+
+    #[inline(always)]
+    fn trunc(self) -> Dual<T> { Dual::constant(self.value.trunc()) }
+
+    #[inline(always)]
+    fn fract(self) -> Dual<T> { Dual::new(self.value.fract(), self.deriv) }
+}
+
+impl<T: Float> EuclidOps for Dual<T> {
+    #[inline(always)]
+    fn div_euclid(self, rhs: Dual<T>) -> Dual<T> {
+        Dual::constant(self.value.div_euclid(rhs.value))
+    }
+
+    #[inline(always)]
+    fn rem_euclid(self, rhs: Dual<T>) -> Dual<T> {
+        let q = self.value.div_euclid(rhs.value);
+        Dual::new(self.value.rem_euclid(rhs.value), self.deriv - rhs.deriv * q)
+    }
+}
+
+impl<T: Float> Classify for Dual<T> {
+    #[inline(always)]
+    fn is_nan(self) -> bool { self.value.is_nan() }
+
+    #[inline(always)]
+    fn is_infinite(self) -> bool { self.value.is_infinite() }
+
+    #[inline(always)]
+    fn is_finite(self) -> bool { self.value.is_finite() }
+
+    #[inline(always)]
+    fn is_normal(self) -> bool { self.value.is_normal() }
+
+    #[inline(always)]
+    fn classify(self) -> FpCategory { self.value.classify() }
+}
+
+impl<T: Float> ApproxEq for Dual<T> {
+    #[inline(always)]
+    fn approx_eq(self, other: Dual<T>, epsilon: Dual<T>) -> bool {
+        self.value.approx_eq(other.value, epsilon.value)
+    }
+
+    #[inline(always)]
+    fn ulps_eq(self, other: Dual<T>, max_ulps: u32) -> bool {
+        self.value.ulps_eq(other.value, max_ulps)
+    }
+}
+
+impl<T: Float> TotalOrder for Dual<T> {
+    #[inline(always)]
+    fn total_cmp(self, other: Dual<T>) -> Ordering { self.value.total_cmp(other.value) }
+}
+
+/// Only the value is encoded; decoding returns a constant.
+impl<T: Float> ToBytes for Dual<T> {
+    type Bytes = T::Bytes;
+
+    #[inline(always)]
+    fn to_le_bytes(self) -> T::Bytes { self.value.to_le_bytes() }
+
+    #[inline(always)]
+    fn to_be_bytes(self) -> T::Bytes { self.value.to_be_bytes() }
+}
+
+impl<T: Float> FromBytes for Dual<T> {
+    #[inline(always)]
+    fn from_le_bytes(bytes: T::Bytes) -> Dual<T> { Dual::constant(T::from_le_bytes(bytes)) }
+
+    #[inline(always)]
+    fn from_be_bytes(bytes: T::Bytes) -> Dual<T> { Dual::constant(T::from_be_bytes(bytes)) }
+}
+
+impl<T: Float> ParseFloat for Dual<T> {
+    #[inline(always)]
+    fn from_str(s: &str) -> Result<Dual<T>, ParseFloatError> { T::from_str(s).map(Dual::constant) }
+}
+
+impl<T: Float> FormatFloat for Dual<T> {
+    #[inline(always)]
+    fn write_with_precision<W: fmt::Write>(self, w: &mut W, digits: usize) -> fmt::Result {
+        self.value.write_with_precision(w, digits)
+    }
+}
+
+macro_rules! constant_impl {
+    ($tr:ident: $($name:ident),*) => {
+        impl<T: Float> $tr for Dual<T> {
+            $(
+                #[inline(always)]
+                fn $name() -> Dual<T> { Dual::constant(<T as $tr>::$name()) }
+            )*
+        }
+    };
+}
+
+constant_impl!(Consts: pi, tau, e, epsilon, min_value, max_value, min_positive, infinity,
+               neg_infinity, nan);
+
+impl<T: Float> One for Dual<T> {
+    #[inline(always)]
+    fn one() -> Dual<T> { Dual::constant(T::one()) }
+}
+
+impl<T: Float> Zero for Dual<T> {
+    #[inline(always)]
+    fn zero() -> Dual<T> { Dual::constant(T::zero()) }
+}
+
+impl<T: Float + ConstOne + ConstZero> ConstOne for Dual<T> {
+    const ONE: Dual<T> = Dual { value: T::ONE, deriv: T::ZERO };
+}
+
+impl<T: Float + ConstZero> ConstZero for Dual<T> {
+    const ZERO: Dual<T> = Dual { value: T::ZERO, deriv: T::ZERO };
+}
+
+impl<T: Float + ConstRadians + ConstZero> ConstRadians for Dual<T> {
+    const QUARTER_TURN: Dual<T> = Dual { value: T::QUARTER_TURN, deriv: T::ZERO };
+    const HALF_TURN: Dual<T> = Dual { value: T::HALF_TURN, deriv: T::ZERO };
+    const FULL_TURN: Dual<T> = Dual { value: T::FULL_TURN, deriv: T::ZERO };
+}
+
+impl<T: Float> Radians for Dual<T> {
+    #[inline(always)]
+    fn _90() -> Dual<T> { Dual::constant(T::_90()) }
+
+    #[inline(always)]
+    fn _180() -> Dual<T> { Dual::constant(T::_180()) }
+
+    #[inline(always)]
+    fn _360() -> Dual<T> { Dual::constant(T::_360()) }
+
+    #[inline(always)]
+    fn deg_to_rad(self) -> Dual<T> { self.chain(self.value.deg_to_rad(), T::one().deg_to_rad()) }
+
+    #[inline(always)]
+    fn rad_to_deg(self) -> Dual<T> { self.chain(self.value.rad_to_deg(), T::one().rad_to_deg()) }
+}
+
+impl<T: Float> Sqrt for Dual<T> {
+    #[inline(always)]
+    fn sqrt(self) -> Dual<T> {
+        let s = self.value.sqrt();
+        self.chain(s, (s + s).recip())
+    }
+}
+
+impl<T: Float> Recip for Dual<T> {
+    #[inline(always)]
+    fn recip(self) -> Dual<T> {
+        let r = self.value.recip();
+        self.chain(r, -r * r)
+    }
+
+    #[inline(always)]
+    fn rsqrt(self) -> Dual<T> {
+        let r = self.value.rsqrt();
+        self.chain(r, -r * r * r / T::from_f64(2.0))
+    }
+}
+
+impl<T: Float> Cbrt for Dual<T> {
+    #[inline(always)]
+    fn cbrt(self) -> Dual<T> {
+        let c = self.value.cbrt();
+        self.chain(c, (T::from_f64(3.0) * c * c).recip())
+    }
+}
+
+impl<T: Float> Trig for Dual<T> {
+    #[inline(always)]
+    fn sin(self) -> Dual<T> {
+        let (s, c) = self.value.sin_cos();
+        self.chain(s, c)
+    }
+
+    #[inline(always)]
+    fn cos(self) -> Dual<T> {
+        let (s, c) = self.value.sin_cos();
+        self.chain(c, -s)
+    }
+
+    #[inline(always)]
+    fn tan(self) -> Dual<T> {
+        let t = self.value.tan();
+        self.chain(t, T::one() + t * t)
+    }
+
+    #[inline(always)]
+    fn asin(self) -> Dual<T> {
+        self.chain(self.value.asin(), (T::one() - self.value * self.value).rsqrt())
+    }
+
+    #[inline(always)]
+    fn acos(self) -> Dual<T> {
+        self.chain(self.value.acos(), -(T::one() - self.value * self.value).rsqrt())
+    }
+
+    #[inline(always)]
+    fn atan(self) -> Dual<T> {
+        self.chain(self.value.atan(), (T::one() + self.value * self.value).recip())
+    }
+
+    #[inline(always)]
+    fn atan2(self, other: Dual<T>) -> Dual<T> {
+        let (y, x) = (self.value, other.value);
+        Dual::new(y.atan2(x), (x * self.deriv - y * other.deriv) / (x * x + y * y))
+    }
+
+    #[inline(always)]
+    fn sinh(self) -> Dual<T> { self.chain(self.value.sinh(), self.value.cosh()) }
+
+    #[inline(always)]
+    fn cosh(self) -> Dual<T> { self.chain(self.value.cosh(), self.value.sinh()) }
+
+    #[inline(always)]
+    fn tanh(self) -> Dual<T> {
+        let t = self.value.tanh();
+        self.chain(t, T::one() - t * t)
+    }
+
+    #[inline(always)]
+    fn asinh(self) -> Dual<T> {
+        self.chain(self.value.asinh(), (self.value * self.value + T::one()).rsqrt())
+    }
+
+    #[inline(always)]
+    fn acosh(self) -> Dual<T> {
+        self.chain(self.value.acosh(), (self.value * self.value - T::one()).rsqrt())
+    }
+
+    #[inline(always)]
+    fn atanh(self) -> Dual<T> {
+        self.chain(self.value.atanh(), (T::one() - self.value * self.value).recip())
+    }
+
+    #[inline(always)]
+    fn sin_cos(self) -> (Dual<T>, Dual<T>) {
+        let (s, c) = self.value.sin_cos();
+        (self.chain(s, c), self.chain(c, -s))
+    }
+}
+
+macro_rules! from_impl {
+    ($($name:ident($t:ty)),*) => {
+        impl<T: Float> FromPrimitive for Dual<T> {
+            $(
+                #[inline(always)]
+                fn $name(t: $t) -> Dual<T> { Dual::constant(T::$name(t)) }
+            )*
+        }
+    };
+}
+
+from_impl!(from_f64(f64), from_f32(f32), from_isize(isize), from_u32(u32), from_i32(i32),
+           from_u64(u64), from_i64(i64), from_usize(usize), from_u8(u8), from_i8(i8),
+           from_u16(u16), from_i16(i16));
+
+macro_rules! to_impl {
+    ($($name:ident -> $t:ty),*) => {
+        impl<T: Float> ToPrimitive for Dual<T> {
+            $(
+                #[inline(always)]
+                fn $name(self) -> $t { self.value.$name() }
+            )*
+        }
+    };
+}
+
+to_impl!(to_f64 -> f64, to_f32 -> f32, to_i64 -> i64, to_u64 -> u64, to_i32 -> i32,
+         to_u32 -> u32, to_isize -> isize, to_usize -> usize,
+         checked_to_i64 -> Option<i64>, checked_to_u64 -> Option<u64>,
+         checked_to_i32 -> Option<i32>, checked_to_u32 -> Option<u32>,
+         checked_to_isize -> Option<isize>, checked_to_usize -> Option<usize>);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Checks the derivative of `f` at `x` against a central difference.
+    fn check<F: Fn(Dual<f64>) -> Dual<f64>>(f: F, x: f64) {
+        let h = 1e-6;
+        let numeric = (f(Dual::constant(x + h)).value - f(Dual::constant(x - h)).value) / (2.0 * h);
+        let exact = f(Dual::variable(x)).deriv;
+        assert!((exact - numeric).abs() < 1e-6 * exact.abs().max(1.0), "{} != {}", exact, numeric);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let x = Dual::variable(3.0_f64);
+        let y = x * x + Dual::constant(2.0) * x - Dual::one();
+        assert_eq!((y.value, y.deriv), (14.0, 8.0));
+        let q = Dual::one() / x;
+        assert_eq!(q.deriv, -1.0 / 9.0);
+        let r = (x + x) % Dual::constant(4.0);
+        assert_eq!((r.value, r.deriv), (2.0, 2.0));
+        assert!(Dual::constant(1.0) < x);
+    }
+
+    #[test]
+    fn test_chain_rule() {
+        for &x in &[0.3, 0.7, 1.5] {
+            check(|x| x.sqrt(), x);
+            check(|x| x.cbrt(), x);
+            check(|x| x.rsqrt(), x);
+            check(|x| x.powf(Dual::constant(2.5)), x);
+            check(|x| x.powf(x), x);
+            check(|x| Dual::constant(2.0).powf(x), x);
+            check(|x| x.powi(3), x);
+            check(|x| x.exp() + x.exp2() + x.exp_m1(), x);
+            check(|x| x.ln() + x.log2() + x.log10() + x.ln_1p(), x);
+            check(|x| x.log(Dual::constant(3.0)), x);
+            check(|x| x.sin() * x.cos() + x.tan(), x);
+            check(|x| x.asin() + x.acos() + x.atan() + x.atanh(), x / 2.0);
+            check(|x| x.sinh() + x.cosh() + x.tanh() + x.asinh(), x);
+            check(|x| (x + Dual::one()).acosh(), x);
+            check(|x| x.atan2(Dual::constant(0.5)) + Dual::constant(0.5).atan2(x), x);
+            check(|x| x.hypot(x.sqr()) - x.abs() * (-x).abs(), x);
+            check(|x| x.mul_add(x, x.cube()), x);
+            check(|x| x.deg_to_rad().sin() + x.rad_to_deg(), x);
+        }
+    }
+
+    #[test]
+    fn test_piecewise() {
+        let x = Dual::variable(2.5_f32);
+        assert_eq!(x.floor().deriv, 0.0);
+        assert_eq!(x.fract().deriv, 1.0);
+        assert_eq!(x.signum().deriv, 0.0);
+        assert_eq!((-x).abs().deriv, 1.0);
+        assert_eq!(x.copysign(Dual::constant(-1.0)).deriv, -1.0);
+        assert_eq!(x.rem_euclid(Dual::constant(-1.0)).deriv, 1.0);
+        assert_eq!(x.max(Dual::constant(3.0)).deriv, 0.0);
+        assert_eq!(x.clamp01().value, 1.0);
+        let (s, c) = Trig::sin_cos(x);
+        assert_eq!((s.deriv, c.deriv), (Trig::cos(2.5_f32), -Trig::sin(2.5_f32)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_clamp_swapped_bounds() {
+        Clamp::clamp(Dual::variable(0.5_f64), Dual::one(), Dual::zero());
+    }
+}
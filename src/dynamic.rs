@@ -0,0 +1,242 @@
+//! Floats of a precision chosen at runtime.
+//!
+//! `Float` returns `Self` from its methods, so it cannot be made into a trait object.
+//! `DynFloat` covers the basic arithmetic through references instead,
+//! and `AnyFloat` holds either an `f32` or an `f64` by value.
+
+use core::fmt;
+use core::mem;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+use {Abs, Float, Sqrt};
+
+/// An object safe subset of `Float`, for holding scalars as `dyn DynFloat`.
+///
+/// Values cross between implementations as `f64`,
+/// so an operand of higher precision is rounded to it first.
+pub trait DynFloat {
+    /// Returns the value as `f64`.
+    fn get_f64(&self) -> f64;
+    /// Sets the value from `f64`, rounding to the precision of self.
+    fn set_f64(&mut self, value: f64);
+    /// Returns the size of the value in bits.
+    fn bit_width(&self) -> u32;
+    /// Adds `rhs` to self.
+    fn add_dyn(&mut self, rhs: &dyn DynFloat);
+    /// Subtracts `rhs` from self.
+    fn sub_dyn(&mut self, rhs: &dyn DynFloat);
+    /// Multiplies self by `rhs`.
+    fn mul_dyn(&mut self, rhs: &dyn DynFloat);
+    /// Divides self by `rhs`.
+    fn div_dyn(&mut self, rhs: &dyn DynFloat);
+    /// Negates self.
+    fn neg_dyn(&mut self);
+    /// Replaces self with its absolute value.
+    fn abs_dyn(&mut self);
+    /// Replaces self with its square root.
+    fn sqrt_dyn(&mut self);
+}
+
+impl<T: Float> DynFloat for T {
+    #[inline(always)]
+    fn get_f64(&self) -> f64 { self.to_f64() }
+
+    #[inline(always)]
+    fn set_f64(&mut self, value: f64) { *self = T::from_f64(value); }
+
+    #[inline(always)]
+    fn bit_width(&self) -> u32 { mem::size_of::<T>() as u32 * 8 }
+
+    #[inline(always)]
+    fn add_dyn(&mut self, rhs: &dyn DynFloat) { *self += T::from_f64(rhs.get_f64()); }
+
+    #[inline(always)]
+    fn sub_dyn(&mut self, rhs: &dyn DynFloat) { *self -= T::from_f64(rhs.get_f64()); }
+
+    #[inline(always)]
+    fn mul_dyn(&mut self, rhs: &dyn DynFloat) { *self *= T::from_f64(rhs.get_f64()); }
+
+    #[inline(always)]
+    fn div_dyn(&mut self, rhs: &dyn DynFloat) { *self /= T::from_f64(rhs.get_f64()); }
+
+    #[inline(always)]
+    fn neg_dyn(&mut self) { *self = -*self; }
+
+    #[inline(always)]
+    fn abs_dyn(&mut self) { *self = self.abs(); }
+
+    #[inline(always)]
+    fn sqrt_dyn(&mut self) { *self = self.sqrt(); }
+}
+
+/// Either an `f32` or an `f64`.
+///
+/// Arithmetic between two `F32` values stays in `f32`,
+/// and anything involving an `F64` is promoted to `f64`.
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub enum AnyFloat {
+    /// Single precision.
+    F32(f32),
+    /// Double precision.
+    F64(f64),
+}
+
+impl AnyFloat {
+    /// Returns the value as `f64`, which is exact for both variants.
+    #[inline(always)]
+    pub fn to_f64(self) -> f64 {
+        match self {
+            AnyFloat::F32(x) => x as f64,
+            AnyFloat::F64(x) => x,
+        }
+    }
+
+    /// Returns `true` if the value is double precision.
+    #[inline(always)]
+    pub fn is_f64(self) -> bool { matches!(self, AnyFloat::F64(_)) }
+
+    /// Applies `f` in the precision of self.
+    #[inline(always)]
+    fn map<F: Fn(f32) -> f32, G: Fn(f64) -> f64>(self, f: F, g: G) -> AnyFloat {
+        match self {
+            AnyFloat::F32(x) => AnyFloat::F32(f(x)),
+            AnyFloat::F64(x) => AnyFloat::F64(g(x)),
+        }
+    }
+
+    /// Reads a value in the precision of `value`, `F32` for 32 bits or fewer and `F64` otherwise.
+    #[inline(always)]
+    fn from_dyn(value: &dyn DynFloat) -> AnyFloat {
+        if value.bit_width() <= 32 {
+            AnyFloat::F32(value.get_f64() as f32)
+        } else {
+            AnyFloat::F64(value.get_f64())
+        }
+    }
+}
+
+impl From<f32> for AnyFloat {
+    #[inline(always)]
+    fn from(x: f32) -> AnyFloat { AnyFloat::F32(x) }
+}
+
+impl From<f64> for AnyFloat {
+    #[inline(always)]
+    fn from(x: f64) -> AnyFloat { AnyFloat::F64(x) }
+}
+
+impl fmt::Display for AnyFloat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            AnyFloat::F32(x) => fmt::Display::fmt(&x, f),
+            AnyFloat::F64(x) => fmt::Display::fmt(&x, f),
+        }
+    }
+}
+
+macro_rules! any_float_op {
+    ($tr:ident, $name:ident, $op:tt) => {
+        impl $tr for AnyFloat {
+            type Output = AnyFloat;
+
+            #[inline(always)]
+            fn $name(self, rhs: AnyFloat) -> AnyFloat {
+                match (self, rhs) {
+                    (AnyFloat::F32(a), AnyFloat::F32(b)) => AnyFloat::F32(a $op b),
+                    (a, b) => AnyFloat::F64(a.to_f64() $op b.to_f64()),
+                }
+            }
+        }
+    };
+}
+
+any_float_op!(Add, add, +);
+any_float_op!(Sub, sub, -);
+any_float_op!(Mul, mul, *);
+any_float_op!(Div, div, /);
+
+impl Neg for AnyFloat {
+    type Output = AnyFloat;
+
+    #[inline(always)]
+    fn neg(self) -> AnyFloat { self.map(|x| -x, |x| -x) }
+}
+
+impl DynFloat for AnyFloat {
+    #[inline(always)]
+    fn get_f64(&self) -> f64 { self.to_f64() }
+
+    #[inline(always)]
+    fn set_f64(&mut self, value: f64) { *self = self.map(|_| value as f32, |_| value); }
+
+    #[inline(always)]
+    fn bit_width(&self) -> u32 { if self.is_f64() { 64 } else { 32 } }
+
+    #[inline(always)]
+    fn add_dyn(&mut self, rhs: &dyn DynFloat) { *self = *self + AnyFloat::from_dyn(rhs); }
+
+    #[inline(always)]
+    fn sub_dyn(&mut self, rhs: &dyn DynFloat) { *self = *self - AnyFloat::from_dyn(rhs); }
+
+    #[inline(always)]
+    fn mul_dyn(&mut self, rhs: &dyn DynFloat) { *self = *self * AnyFloat::from_dyn(rhs); }
+
+    #[inline(always)]
+    fn div_dyn(&mut self, rhs: &dyn DynFloat) { *self = *self / AnyFloat::from_dyn(rhs); }
+
+    #[inline(always)]
+    fn neg_dyn(&mut self) { *self = -*self; }
+
+    #[inline(always)]
+    fn abs_dyn(&mut self) { *self = self.map(Abs::abs, Abs::abs); }
+
+    #[inline(always)]
+    fn sqrt_dyn(&mut self) { *self = self.map(Sqrt::sqrt, Sqrt::sqrt); }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_dyn_float() {
+        let (mut a, mut b, mut c) = (1.5_f32, 2.25_f64, AnyFloat::F32(4.0));
+        {
+            let values: [&mut dyn DynFloat; 3] = [&mut a, &mut b, &mut c];
+            for v in values {
+                v.mul_dyn(&3.0_f64);
+                v.sub_dyn(&AnyFloat::F32(0.5));
+                v.sqrt_dyn();
+                v.neg_dyn();
+                v.abs_dyn();
+                v.add_dyn(&1.0_f32);
+            }
+        }
+        assert_eq!((a, b), (3.0, 3.5));
+        // Multiplying by an `f64` promoted `c`.
+        assert_eq!(c, AnyFloat::F64(Sqrt::sqrt(11.5_f64) + 1.0));
+        let mut third = 1.0_f32;
+        third.div_dyn(&3.0_f64);
+        assert_eq!(third, 1.0 / 3.0);
+        third.set_f64(0.1);
+        assert_eq!((third.get_f64(), third.bit_width()), (0.1_f32 as f64, 32));
+        assert_eq!(2.0_f64.bit_width(), 64);
+    }
+
+    #[test]
+    fn test_any_float() {
+        let (x, y) = (AnyFloat::from(0.1_f32), AnyFloat::from(0.2_f64));
+        assert_eq!(x + x, AnyFloat::F32(0.2));
+        assert_eq!(x + y, AnyFloat::F64(0.1_f32 as f64 + 0.2));
+        assert_eq!(y - x, AnyFloat::F64(0.2 - 0.1_f32 as f64));
+        assert_eq!(x * AnyFloat::F32(2.0), AnyFloat::F32(0.2));
+        assert_eq!(-(y / AnyFloat::F32(2.0)), AnyFloat::F64(-0.1));
+        assert!(x < y && y.is_f64() && !x.is_f64());
+        // An `F32` takes on the precision of a wider operand.
+        let mut z = x;
+        z.add_dyn(&0.2_f64);
+        assert_eq!(z, AnyFloat::F64(0.1_f32 as f64 + 0.2));
+        z.set_f64(1.0 / 3.0);
+        assert_eq!((z.to_f64(), z.bit_width()), (1.0 / 3.0, 64));
+    }
+}
@@ -0,0 +1,293 @@
+//! Easing functions for tweening.
+//!
+//! Each function maps a normalized time `t` in `[0, 1]` to a progress value,
+//! with `0` at `t = 0` and `1` at `t = 1`.
+//! `Back` and `Elastic` overshoot the `[0, 1]` range in between.
+//! See <https://easings.net> for plots of each curve.
+
+use Float;
+
+/// Returns `t` unchanged.
+#[inline(always)]
+pub fn linear<T: Float>(t: T) -> T { t }
+
+/// Accelerates from zero velocity with a quadratic curve.
+#[inline(always)]
+pub fn ease_in_quad<T: Float>(t: T) -> T { t * t }
+
+/// Decelerates to zero velocity with a quadratic curve.
+#[inline(always)]
+pub fn ease_out_quad<T: Float>(t: T) -> T { T::one() - ease_in_quad(T::one() - t) }
+
+/// Accelerates until halfway, then decelerates, with a quadratic curve.
+#[inline(always)]
+pub fn ease_in_out_quad<T: Float>(t: T) -> T { in_out(t, ease_in_quad) }
+
+/// Accelerates from zero velocity with a cubic curve.
+#[inline(always)]
+pub fn ease_in_cubic<T: Float>(t: T) -> T { t * t * t }
+
+/// Decelerates to zero velocity with a cubic curve.
+#[inline(always)]
+pub fn ease_out_cubic<T: Float>(t: T) -> T { T::one() - ease_in_cubic(T::one() - t) }
+
+/// Accelerates until halfway, then decelerates, with a cubic curve.
+#[inline(always)]
+pub fn ease_in_out_cubic<T: Float>(t: T) -> T { in_out(t, ease_in_cubic) }
+
+/// Accelerates from zero velocity with a quartic curve.
+#[inline(always)]
+pub fn ease_in_quart<T: Float>(t: T) -> T { t * t * t * t }
+
+/// Decelerates to zero velocity with a quartic curve.
+#[inline(always)]
+pub fn ease_out_quart<T: Float>(t: T) -> T { T::one() - ease_in_quart(T::one() - t) }
+
+/// Accelerates until halfway, then decelerates, with a quartic curve.
+#[inline(always)]
+pub fn ease_in_out_quart<T: Float>(t: T) -> T { in_out(t, ease_in_quart) }
+
+/// Accelerates from zero velocity along a quarter sine wave.
+#[inline(always)]
+pub fn ease_in_sine<T: Float>(t: T) -> T { T::one() - (t * T::_90()).cos() }
+
+/// Decelerates to zero velocity along a quarter sine wave.
+#[inline(always)]
+pub fn ease_out_sine<T: Float>(t: T) -> T { (t * T::_90()).sin() }
+
+/// Accelerates until halfway, then decelerates, along a half sine wave.
+#[inline(always)]
+pub fn ease_in_out_sine<T: Float>(t: T) -> T {
+    (T::one() - (t * T::_180()).cos()) / T::from_f64(2.0)
+}
+
+/// Accelerates from zero velocity exponentially.
+#[inline(always)]
+pub fn ease_in_expo<T: Float>(t: T) -> T {
+    if t <= T::zero() { T::zero() } else { (T::from_f64(10.0) * (t - T::one())).exp2() }
+}
+
+/// Decelerates to zero velocity exponentially.
+#[inline(always)]
+pub fn ease_out_expo<T: Float>(t: T) -> T { T::one() - ease_in_expo(T::one() - t) }
+
+/// Accelerates until halfway, then decelerates, exponentially.
+#[inline(always)]
+pub fn ease_in_out_expo<T: Float>(t: T) -> T { in_out(t, ease_in_expo) }
+
+/// Backs up slightly before accelerating.
+#[inline(always)]
+pub fn ease_in_back<T: Float>(t: T) -> T {
+    let c1 = T::from_f64(1.70158);
+    t * t * ((c1 + T::one()) * t - c1)
+}
+
+/// Overshoots the target slightly before settling.
+#[inline(always)]
+pub fn ease_out_back<T: Float>(t: T) -> T { T::one() - ease_in_back(T::one() - t) }
+
+/// Backs up at the start and overshoots at the end.
+#[inline(always)]
+pub fn ease_in_out_back<T: Float>(t: T) -> T {
+    // Each half is squeezed into half the time, so the overshoot is scaled up
+    // to keep it at about 10% like `ease_in_back`.
+    let c2 = T::from_f64(1.70158 * 1.525);
+    in_out(t, |t| t * t * ((c2 + T::one()) * t - c2))
+}
+
+/// Oscillates with growing amplitude before snapping to the target.
+#[inline(always)]
+pub fn ease_in_elastic<T: Float>(t: T) -> T {
+    if t <= T::zero() { return T::zero(); }
+    if t >= T::one() { return T::one(); }
+    let c4 = T::_360() / T::from_f64(3.0);
+    let ten = T::from_f64(10.0);
+    -(ten * (t - T::one())).exp2() * ((ten * t - T::from_f64(10.75)) * c4).sin()
+}
+
+/// Overshoots the target and oscillates with decaying amplitude.
+#[inline(always)]
+pub fn ease_out_elastic<T: Float>(t: T) -> T { T::one() - ease_in_elastic(T::one() - t) }
+
+/// Oscillates at both ends.
+#[inline(always)]
+pub fn ease_in_out_elastic<T: Float>(t: T) -> T { in_out(t, ease_in_elastic) }
+
+/// Bounces with growing height before reaching the target.
+#[inline(always)]
+pub fn ease_in_bounce<T: Float>(t: T) -> T { T::one() - ease_out_bounce(T::one() - t) }
+
+/// Bounces on the target with decaying height.
+#[inline(always)]
+pub fn ease_out_bounce<T: Float>(t: T) -> T {
+    let n1 = T::from_f64(7.5625);
+    let d1 = T::from_f64(2.75);
+    let bounce = |offset: f64, height: f64| {
+        let t = t - T::from_f64(offset) / d1;
+        n1 * t * t + T::from_f64(height)
+    };
+    if t < T::one() / d1 {
+        n1 * t * t
+    } else if t < T::from_f64(2.0) / d1 {
+        bounce(1.5, 0.75)
+    } else if t < T::from_f64(2.5) / d1 {
+        bounce(2.25, 0.9375)
+    } else {
+        bounce(2.625, 0.984375)
+    }
+}
+
+/// Bounces at both ends.
+#[inline(always)]
+pub fn ease_in_out_bounce<T: Float>(t: T) -> T { in_out(t, ease_in_bounce) }
+
+/// Builds an in-out easing from an in easing,
+/// by running it at double speed and mirroring the second half.
+#[inline(always)]
+fn in_out<T: Float, F: Fn(T) -> T>(t: T, ease_in: F) -> T {
+    let two = T::from_f64(2.0);
+    if t < T::from_f64(0.5) {
+        ease_in(two * t) / two
+    } else {
+        T::one() - ease_in(two - two * t) / two
+    }
+}
+
+/// An easing function, for data-driven tweens.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Easing {
+    /// `linear`.
+    Linear,
+    /// `ease_in_quad`.
+    InQuad,
+    /// `ease_out_quad`.
+    OutQuad,
+    /// `ease_in_out_quad`.
+    InOutQuad,
+    /// `ease_in_cubic`.
+    InCubic,
+    /// `ease_out_cubic`.
+    OutCubic,
+    /// `ease_in_out_cubic`.
+    InOutCubic,
+    /// `ease_in_quart`.
+    InQuart,
+    /// `ease_out_quart`.
+    OutQuart,
+    /// `ease_in_out_quart`.
+    InOutQuart,
+    /// `ease_in_sine`.
+    InSine,
+    /// `ease_out_sine`.
+    OutSine,
+    /// `ease_in_out_sine`.
+    InOutSine,
+    /// `ease_in_expo`.
+    InExpo,
+    /// `ease_out_expo`.
+    OutExpo,
+    /// `ease_in_out_expo`.
+    InOutExpo,
+    /// `ease_in_back`.
+    InBack,
+    /// `ease_out_back`.
+    OutBack,
+    /// `ease_in_out_back`.
+    InOutBack,
+    /// `ease_in_elastic`.
+    InElastic,
+    /// `ease_out_elastic`.
+    OutElastic,
+    /// `ease_in_out_elastic`.
+    InOutElastic,
+    /// `ease_in_bounce`.
+    InBounce,
+    /// `ease_out_bounce`.
+    OutBounce,
+    /// `ease_in_out_bounce`.
+    InOutBounce,
+}
+
+impl Easing {
+    /// Applies the easing function to `t`.
+    pub fn apply<T: Float>(self, t: T) -> T {
+        use self::Easing::*;
+
+        match self {
+            Linear => linear(t),
+            InQuad => ease_in_quad(t),
+            OutQuad => ease_out_quad(t),
+            InOutQuad => ease_in_out_quad(t),
+            InCubic => ease_in_cubic(t),
+            OutCubic => ease_out_cubic(t),
+            InOutCubic => ease_in_out_cubic(t),
+            InQuart => ease_in_quart(t),
+            OutQuart => ease_out_quart(t),
+            InOutQuart => ease_in_out_quart(t),
+            InSine => ease_in_sine(t),
+            OutSine => ease_out_sine(t),
+            InOutSine => ease_in_out_sine(t),
+            InExpo => ease_in_expo(t),
+            OutExpo => ease_out_expo(t),
+            InOutExpo => ease_in_out_expo(t),
+            InBack => ease_in_back(t),
+            OutBack => ease_out_back(t),
+            InOutBack => ease_in_out_back(t),
+            InElastic => ease_in_elastic(t),
+            OutElastic => ease_out_elastic(t),
+            InOutElastic => ease_in_out_elastic(t),
+            InBounce => ease_in_bounce(t),
+            OutBounce => ease_out_bounce(t),
+            InOutBounce => ease_in_out_bounce(t),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ApproxEq;
+
+    const ALL: [Easing; 25] = {
+        use self::Easing::*;
+        [
+            Linear, InQuad, OutQuad, InOutQuad, InCubic, OutCubic, InOutCubic,
+            InQuart, OutQuart, InOutQuart, InSine, OutSine, InOutSine,
+            InExpo, OutExpo, InOutExpo, InBack, OutBack, InOutBack,
+            InElastic, OutElastic, InOutElastic, InBounce, OutBounce, InOutBounce,
+        ]
+    };
+
+    #[test]
+    fn test_endpoints() {
+        for &e in &ALL {
+            assert!(e.apply(0.0_f64).approx_eq(0.0, 1e-3), "{:?}", e);
+            assert!(e.apply(1.0_f64).approx_eq(1.0, 1e-3), "{:?}", e);
+            assert!(e.apply(0.0_f32).approx_eq(0.0, 1e-3), "{:?}", e);
+            assert!(e.apply(1.0_f32).approx_eq(1.0, 1e-3), "{:?}", e);
+        }
+    }
+
+    #[test]
+    fn test_symmetry() {
+        use self::Easing::*;
+
+        for &e in &[Linear, InOutQuad, InOutCubic, InOutQuart, InOutSine, InOutExpo,
+                    InOutBack, InOutElastic, InOutBounce] {
+            assert!(e.apply(0.5_f64).approx_eq(0.5, 1e-12), "{:?}", e);
+            assert!((e.apply(0.2_f64) + e.apply(0.8)).approx_eq(1.0, 1e-12), "{:?}", e);
+        }
+    }
+
+    #[test]
+    fn test_values() {
+        assert_eq!(ease_in_quad(0.5_f64), 0.25);
+        assert_eq!(ease_out_cubic(0.5_f64), 0.875);
+        assert!(ease_in_out_sine(0.25_f64).approx_eq(0.5 - 0.5 / 2.0_f64.sqrt(), 1e-15));
+        assert!(ease_in_back(0.3_f64) < 0.0);
+        assert!(ease_out_back(0.7_f64) > 1.0);
+        assert!(ease_out_elastic(0.2_f64) > 1.0);
+        assert!(ease_out_bounce(0.5_f64).approx_eq(0.765625, 1e-12));
+        assert_eq!(Easing::OutQuad.apply(0.5_f32), ease_out_quad(0.5));
+    }
+}
@@ -0,0 +1,215 @@
+//! Scoped changes to the floating point environment.
+//!
+//! Supported on `x86_64`, `x86` with SSE2 and `aarch64`, through the MXCSR and FPCR registers.
+//! Elsewhere the changes are no-ops.
+//! The settings belong to the current thread, and are not inherited by threads spawned from it.
+
+use core::marker::PhantomData;
+
+use RoundingMode;
+
+#[cfg(any(target_arch = "x86_64", all(target_arch = "x86", target_feature = "sse2")))]
+mod reg {
+    use core::arch::asm;
+
+    use RoundingMode;
+
+    pub type Control = u32;
+
+    pub const SUPPORTED: bool = true;
+    // Flush to zero and denormals are zero.
+    pub const FLUSH: Control = 0x8040;
+    pub const ROUNDING: Control = 0x6000;
+    // The sticky exception flags, which are kept on restore.
+    pub const STATUS: Control = 0x003f;
+
+    pub fn rounding(mode: RoundingMode) -> Control {
+        match mode {
+            RoundingMode::Round => 0x0000,
+            RoundingMode::Floor => 0x2000,
+            RoundingMode::Ceil => 0x4000,
+            RoundingMode::Trunc => 0x6000,
+        }
+    }
+
+    #[inline(always)]
+    pub fn get() -> Control {
+        let mut csr: Control = 0;
+        unsafe { asm!("stmxcsr [{}]", in(reg) &mut csr, options(nostack, preserves_flags)) };
+        csr
+    }
+
+    #[inline(always)]
+    pub fn set(csr: Control) {
+        unsafe { asm!("ldmxcsr [{}]", in(reg) &csr, options(nostack, readonly, preserves_flags)) };
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod reg {
+    use core::arch::asm;
+
+    use RoundingMode;
+
+    pub type Control = u64;
+
+    pub const SUPPORTED: bool = true;
+    // Flush to zero, which covers inputs as well.
+    pub const FLUSH: Control = 1 << 24;
+    pub const ROUNDING: Control = 3 << 22;
+    // The exception flags live in FPSR, which is never touched.
+    pub const STATUS: Control = 0;
+
+    pub fn rounding(mode: RoundingMode) -> Control {
+        match mode {
+            RoundingMode::Round => 0,
+            RoundingMode::Ceil => 1 << 22,
+            RoundingMode::Floor => 2 << 22,
+            RoundingMode::Trunc => 3 << 22,
+        }
+    }
+
+    #[inline(always)]
+    pub fn get() -> Control {
+        let fpcr: Control;
+        unsafe { asm!("mrs {}, fpcr", out(reg) fpcr, options(nomem, nostack, preserves_flags)) };
+        fpcr
+    }
+
+    #[inline(always)]
+    pub fn set(fpcr: Control) {
+        unsafe { asm!("msr fpcr, {}", in(reg) fpcr, options(nomem, nostack, preserves_flags)) };
+    }
+}
+
+#[cfg(not(any(
+    target_arch = "x86_64",
+    all(target_arch = "x86", target_feature = "sse2"),
+    target_arch = "aarch64",
+)))]
+mod reg {
+    use RoundingMode;
+
+    pub type Control = u32;
+
+    pub const SUPPORTED: bool = false;
+    pub const FLUSH: Control = 0;
+    pub const ROUNDING: Control = 0;
+    pub const STATUS: Control = 0;
+
+    pub fn rounding(_: RoundingMode) -> Control { 0 }
+
+    #[inline(always)]
+    pub fn get() -> Control { 0 }
+
+    #[inline(always)]
+    pub fn set(_: Control) {}
+}
+
+/// Restores the saved control register when dropped,
+/// keeping the exception flags raised in between.
+///
+/// Holds a raw pointer marker, so it cannot be sent to another thread's environment.
+struct Restore {
+    saved: reg::Control,
+    _thread: PhantomData<*const ()>,
+}
+
+impl Restore {
+    fn update(mask: reg::Control, bits: reg::Control) -> Restore {
+        let saved = reg::get();
+        reg::set(saved & !mask | bits);
+        Restore { saved, _thread: PhantomData }
+    }
+}
+
+impl Drop for Restore {
+    fn drop(&mut self) { reg::set(self.saved & !reg::STATUS | reg::get() & reg::STATUS); }
+}
+
+/// Flushes subnormal numbers to zero until dropped.
+///
+/// Arithmetic on subnormals is many times slower on most CPUs,
+/// which shows up in audio filters and physics decaying towards zero.
+/// With the guard alive, subnormal inputs and results are treated as zero of the same sign.
+/// Dropping it restores the previous setting, so guards can be nested.
+pub struct DenormalsFlushGuard {
+    _restore: Restore,
+}
+
+impl DenormalsFlushGuard {
+    /// Enables flushing for the current thread.
+    ///
+    /// # Safety
+    ///
+    /// Rust assumes the default floating point environment, and may evaluate float operations
+    /// at compile time or move them across the change, so results can follow either setting.
+    /// While the guard is alive, no code may rely on float results for memory safety,
+    /// including code in other crates that is called in the meantime.
+    /// Guards must be dropped in the reverse order of their creation.
+    pub unsafe fn new() -> DenormalsFlushGuard {
+        DenormalsFlushGuard { _restore: Restore::update(reg::FLUSH, reg::FLUSH) }
+    }
+
+    /// Returns `true` if the target supports flushing, so the guard is not a no-op.
+    pub fn is_supported() -> bool { reg::SUPPORTED }
+}
+
+/// Calls `f` with the hardware rounding mode of the current thread set to `mode`,
+/// then restores the previous mode, also if `f` panics.
+///
+/// `Round` rounds to nearest with ties to even, the default.
+/// Only affects basic arithmetic and `Sqrt` on supported targets,
+/// and the other math functions may not honour it.
+/// Passing operands through `core::hint::black_box` keeps them from being evaluated early.
+///
+/// # Safety
+///
+/// Rust assumes the default floating point environment, and may evaluate float operations
+/// at compile time or move them across the change, so results can follow either mode.
+/// Neither `f` nor anything it calls may rely on float results for memory safety.
+pub unsafe fn with_rounding_mode<R, F: FnOnce() -> R>(mode: RoundingMode, f: F) -> R {
+    let _restore = Restore::update(reg::ROUNDING, reg::rounding(mode));
+    f()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use core::hint::black_box;
+
+    #[test]
+    fn test_flush() {
+        let tiny = f64::MIN_POSITIVE;
+        {
+            let _guard = unsafe { DenormalsFlushGuard::new() };
+            let _nested = unsafe { DenormalsFlushGuard::new() };
+            let half = black_box(tiny) / black_box(2.0);
+            assert_eq!(half == 0.0, DenormalsFlushGuard::is_supported());
+        }
+        assert!(black_box(tiny) / black_box(2.0) > 0.0);
+    }
+
+    #[test]
+    fn test_rounding_mode() {
+        let third = |mode| unsafe { with_rounding_mode(mode, || black_box(1.0_f32) / black_box(3.0)) };
+        let (down, up) = (third(RoundingMode::Floor), third(RoundingMode::Ceil));
+        let nearest = third(RoundingMode::Round);
+        assert_eq!(third(RoundingMode::Trunc), down);
+        assert_eq!(nearest, black_box(1.0_f32) / black_box(3.0));
+        if DenormalsFlushGuard::is_supported() {
+            assert!(down < up);
+            let negative =
+                unsafe { with_rounding_mode(RoundingMode::Ceil, || black_box(-1.0_f32) / black_box(3.0)) };
+            assert_eq!(negative, -down);
+        }
+    }
+
+    #[test]
+    fn test_status_kept() {
+        reg::set(reg::get() & !reg::STATUS);
+        unsafe { with_rounding_mode(RoundingMode::Ceil, || black_box(1.0_f64) / black_box(3.0)) };
+        // The division raised the inexact flag, which survives the restore.
+        assert_eq!(reg::get() & reg::STATUS != 0, reg::STATUS != 0);
+    }
+}
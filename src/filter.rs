@@ -0,0 +1,202 @@
+//! Stateful filters for smoothing values over time.
+//!
+//! Each filter is advanced with `update(dt, target)` once per frame.
+//! They are exact for any time step, so the result does not depend on the frame rate
+//! beyond the target being sampled at each update.
+
+use Float;
+
+/// Exponential smoothing towards a target, a first-order low-pass filter.
+///
+/// Closes about 63% of the distance to a constant target per time constant.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Smoothed<T> {
+    value: T,
+    time_constant: T,
+}
+
+impl<T: Float> Smoothed<T> {
+    /// Starts at `value`.
+    ///
+    /// A time constant of zero or less follows the target without lag.
+    #[inline(always)]
+    pub fn new(value: T, time_constant: T) -> Smoothed<T> {
+        Smoothed { value, time_constant }
+    }
+
+    /// Advances the filter by `dt` and returns the new value.
+    #[inline(always)]
+    pub fn update(&mut self, dt: T, target: T) -> T {
+        if self.time_constant <= T::zero() {
+            self.value = target;
+        } else {
+            let alpha = -(-dt / self.time_constant).exp_m1();
+            self.value += (target - self.value) * alpha;
+        }
+        self.value
+    }
+
+    /// Returns the current value.
+    #[inline(always)]
+    pub fn value(&self) -> T { self.value }
+
+    /// Jumps to `value` without smoothing.
+    #[inline(always)]
+    pub fn reset(&mut self, value: T) { self.value = value; }
+}
+
+/// A critically damped spring pulling a value towards a target.
+///
+/// Moves like a spring with just enough damping to never overshoot a constant target,
+/// and keeps its velocity when the target moves, which makes camera motion look natural.
+/// Settles to within 1% after about 6.6 time constants.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SpringDamper<T> {
+    value: T,
+    velocity: T,
+    time_constant: T,
+}
+
+impl<T: Float> SpringDamper<T> {
+    /// Starts at rest at `value`.
+    ///
+    /// A time constant of zero or less follows the target without lag.
+    #[inline(always)]
+    pub fn new(value: T, time_constant: T) -> SpringDamper<T> {
+        SpringDamper { value, velocity: T::zero(), time_constant }
+    }
+
+    /// Advances the spring by `dt` and returns the new value.
+    #[inline(always)]
+    pub fn update(&mut self, dt: T, target: T) -> T {
+        if self.time_constant <= T::zero() {
+            self.value = target;
+            self.velocity = T::zero();
+            return target;
+        }
+        // The exact solution of `x'' + 2ωx' + ω²x = 0` for the offset from the target.
+        let omega = self.time_constant.recip();
+        let offset = self.value - target;
+        let decay = (-omega * dt).exp();
+        let temp = (self.velocity + omega * offset) * dt;
+        self.velocity = (self.velocity - omega * temp) * decay;
+        self.value = target + (offset + temp) * decay;
+        self.value
+    }
+
+    /// Returns the current value.
+    #[inline(always)]
+    pub fn value(&self) -> T { self.value }
+
+    /// Returns the current velocity, in units per unit of `dt`.
+    #[inline(always)]
+    pub fn velocity(&self) -> T { self.velocity }
+
+    /// Jumps to `value` and stops.
+    #[inline(always)]
+    pub fn reset(&mut self, value: T) {
+        self.value = value;
+        self.velocity = T::zero();
+    }
+}
+
+/// Follows a target at a limited rate of change.
+///
+/// Moves straight to the target when it is within reach,
+/// and by `max_rate * dt` in its direction otherwise.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SlewLimiter<T> {
+    value: T,
+    max_rate: T,
+}
+
+impl<T: Float> SlewLimiter<T> {
+    /// Starts at `value`, moving at most `max_rate` per unit of `dt`.
+    ///
+    /// The sign of `max_rate` is ignored, and an infinite rate follows the target without lag.
+    #[inline(always)]
+    pub fn new(value: T, max_rate: T) -> SlewLimiter<T> {
+        SlewLimiter { value, max_rate }
+    }
+
+    /// Advances the limiter by `dt` and returns the new value.
+    ///
+    /// A negative or NaN `dt` leaves the value in place.
+    #[inline(always)]
+    pub fn update(&mut self, dt: T, target: T) -> T {
+        if self.max_rate.is_infinite() {
+            self.value = target;
+            return target;
+        }
+        // `max` ignores NaN, so the bounds stay ordered for `clamp`.
+        let step = (self.max_rate.abs() * dt).max(T::zero());
+        self.value += (target - self.value).clamp(-step, step);
+        self.value
+    }
+
+    /// Returns the current value.
+    #[inline(always)]
+    pub fn value(&self) -> T { self.value }
+
+    /// Jumps to `value` without limiting.
+    #[inline(always)]
+    pub fn reset(&mut self, value: T) { self.value = value; }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use Exp;
+
+    #[test]
+    fn test_smoothed() {
+        let mut s = Smoothed::new(0.0_f64, 0.5);
+        let x = s.update(0.5, 10.0);
+        assert!((x - 10.0 * (1.0 - Exp::exp(-1.0_f64))).abs() < 1e-12);
+        // Two half steps land where one full step does.
+        let (mut a, mut b) = (Smoothed::new(0.0_f64, 0.25), Smoothed::new(0.0_f64, 0.25));
+        a.update(0.1, 1.0);
+        a.update(0.1, 1.0);
+        assert!((a.value() - b.update(0.2, 1.0)).abs() < 1e-15);
+        let mut instant = Smoothed::new(0.0_f32, 0.0);
+        assert_eq!(instant.update(0.0, 3.0), 3.0);
+        instant.reset(-1.0);
+        assert_eq!(instant.value(), -1.0);
+    }
+
+    #[test]
+    fn test_spring_damper() {
+        let mut s = SpringDamper::new(0.0_f64, 0.1);
+        let mut last = 0.0;
+        for _ in 0..200 {
+            let x = s.update(1.0 / 60.0, 1.0);
+            // Critical damping never overshoots or turns back.
+            assert!((last..=1.0).contains(&x));
+            last = x;
+        }
+        assert!((last - 1.0).abs() < 1e-6 && s.velocity().abs() < 1e-5);
+        let (mut a, mut b) = (SpringDamper::new(2.0_f64, 0.3), SpringDamper::new(2.0_f64, 0.3));
+        for _ in 0..10 { a.update(0.01, -1.0); }
+        assert!((a.value() - b.update(0.1, -1.0)).abs() < 1e-12);
+        assert!((a.velocity() - b.velocity()).abs() < 1e-12);
+        b.reset(5.0);
+        assert_eq!((b.value(), b.velocity()), (5.0, 0.0));
+    }
+
+    #[test]
+    fn test_slew_limiter() {
+        let mut s = SlewLimiter::new(0.0_f32, 2.0);
+        assert_eq!(s.update(0.25, 10.0), 0.5);
+        assert_eq!(s.update(0.25, -10.0), 0.0);
+        assert_eq!(s.update(1.0, 1.5), 1.5);
+        assert_eq!(s.update(1.0, 1.5), 1.5);
+        s.reset(4.0);
+        assert_eq!(s.value(), 4.0);
+        assert_eq!(s.update(-1.0, 0.0), 4.0);
+        assert_eq!(s.update(f32::NAN, 0.0), 4.0);
+        let mut unlimited = SlewLimiter::new(0.0_f64, f64::INFINITY);
+        assert_eq!(unlimited.update(0.0, 1.0), 1.0);
+        assert_eq!(SlewLimiter::new(0.0_f64, f64::NEG_INFINITY).update(0.5, -3.0), -3.0);
+        assert_eq!(SlewLimiter::new(0.0_f64, -2.0).update(0.5, 5.0), 1.0);
+    }
+}
@@ -0,0 +1,285 @@
+//! Conversion to and from strings.
+
+use core::{fmt, str};
+
+use Scalb;
+
+/// The error returned when parsing a float fails.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseFloatError {
+    _private: (),
+}
+
+impl ParseFloatError {
+    pub(crate) const fn new() -> ParseFloatError { ParseFloatError { _private: () } }
+}
+
+impl fmt::Display for ParseFloatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { f.write_str("invalid float literal") }
+}
+
+#[cfg(feature = "std")]
+impl ::std::error::Error for ParseFloatError {}
+
+/// Parsing from strings.
+pub trait ParseFloat: Sized {
+    /// Parses a decimal or hexadecimal float.
+    ///
+    /// Accepts everything `str::parse` does, plus hexadecimal floats such as `0x1.8p3`
+    /// or `-0XAp-2`, where the binary exponent is optional.
+    /// Both forms are correctly rounded, except decimal input for `f128`,
+    /// which is only within a few ulps unless it is short.
+    fn from_str(s: &str) -> Result<Self, ParseFloatError>;
+}
+
+/// Formatting with a fixed number of decimals.
+pub trait FormatFloat: Sized {
+    /// Writes self with `digits` digits after the decimal point.
+    fn write_with_precision<W: fmt::Write>(self, w: &mut W, digits: usize) -> fmt::Result;
+
+    /// Returns self with `digits` digits after the decimal point.
+    ///
+    /// Requires the `std` feature; use `write_with_precision` otherwise.
+    #[cfg(feature = "std")]
+    fn to_string_with_precision(self, digits: usize) -> String {
+        let mut s = String::new();
+        self.write_with_precision(&mut s, digits).expect("writing to a String cannot fail");
+        s
+    }
+}
+
+/// Splits a hexadecimal float into its sign, a mantissa and a binary exponent.
+///
+/// Digits beyond the width of the mantissa are folded into its lowest bit,
+/// which is enough to round correctly.
+fn parse_hex(s: &str) -> Option<(bool, u128, i32)> {
+    let (negative, s) = split_sign(s);
+    if !(s.starts_with("0x") || s.starts_with("0X")) { return None; }
+    let s = &s[2..];
+    let (digits, mut exp) = match s.find(['p', 'P']) {
+        Some(i) => (&s[..i], parse_exp(&s[i + 1..])?),
+        None => (s, 0),
+    };
+    let (mut mantissa, mut any, mut point) = (0_u128, false, false);
+    for c in digits.chars() {
+        if c == '.' {
+            if point { return None; }
+            point = true;
+            continue;
+        }
+        let d = u128::from(c.to_digit(16)?);
+        any = true;
+        // Keeps the mantissa below `2^120`, which is precise enough for every float type.
+        if mantissa >> 116 == 0 {
+            mantissa = mantissa << 4 | d;
+            if point { exp = exp.saturating_sub(4); }
+        } else {
+            mantissa |= (d != 0) as u128;
+            if !point { exp = exp.saturating_add(4); }
+        }
+    }
+    if any { Some((negative, mantissa, exp)) } else { None }
+}
+
+/// Parses a decimal exponent, saturating far outside the range of any float.
+fn parse_exp(s: &str) -> Option<i32> {
+    let (negative, digits) = split_sign(s);
+    if digits.is_empty() { return None; }
+    let mut exp = 0_i32;
+    for c in digits.chars() {
+        exp = (exp * 10 + c.to_digit(10)? as i32).min(100_000);
+    }
+    Some(if negative { -exp } else { exp })
+}
+
+fn split_sign(s: &str) -> (bool, &str) {
+    match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    }
+}
+
+fn from_decimal<T: str::FromStr>(s: &str) -> Result<T, ParseFloatError> {
+    s.parse().map_err(|_| ParseFloatError::new())
+}
+
+macro_rules! parse_impl {
+    ($t:ident, $digits:expr, $min_exp:expr, $decimal:path) => {
+        impl ParseFloat for $t {
+            fn from_str(s: &str) -> Result<$t, ParseFloatError> {
+                let (negative, mantissa, exp) = match parse_hex(s) {
+                    Some(hex) => hex,
+                    None => return $decimal(s),
+                };
+                let value = if mantissa == 0 {
+                    0.0
+                } else {
+                    // Exponent of the lowest bit of a subnormal.
+                    let min_lsb = $min_exp - ($digits - 1);
+                    let top = exp + 127 - mantissa.leading_zeros() as i32;
+                    let shift = min_lsb - exp;
+                    if top >= $min_exp || shift <= 0 {
+                        // The cast rounds correctly and the scaling is exact.
+                        Scalb::ldexp(mantissa as $t, exp)
+                    } else if shift > 120 {
+                        0.0
+                    } else {
+                        // Subnormal, so round once at the subnormal precision.
+                        let (r, rem) = (mantissa >> shift, mantissa & ((1 << shift) - 1));
+                        let half = 1 << (shift - 1);
+                        let r = if rem > half || (rem == half && r & 1 == 1) { r + 1 } else { r };
+                        Scalb::ldexp(r as $t, min_lsb)
+                    }
+                };
+                Ok(if negative { -value } else { value })
+            }
+        }
+    };
+}
+
+macro_rules! fmt_impl {
+    ($t:ident, $digits:expr, $min_exp:expr) => {
+        parse_impl!($t, $digits, $min_exp, from_decimal);
+
+        impl FormatFloat for $t {
+            #[inline(always)]
+            fn write_with_precision<W: fmt::Write>(self, w: &mut W, digits: usize) -> fmt::Result {
+                write!(w, "{:.*}", digits, self)
+            }
+        }
+    };
+}
+
+fmt_impl!(f32, 24, -126);
+fmt_impl!(f64, 53, -1022);
+
+#[cfg(feature = "f128")]
+parse_impl!(f128, 113, -16382, f128_from_decimal);
+
+/// Returns `x * 10^n`, in two steps where a single power of ten would overflow.
+#[cfg(feature = "f128")]
+fn f128_scale10(x: f128, n: i32) -> f128 {
+    let scale = |x: f128, n: i32| if n < 0 { x / 10.0_f128.powi(-n) } else { x * 10.0_f128.powi(n) };
+    if n.abs() <= 4900 { scale(x, n) } else { scale(scale(x, n / 2), n - n / 2) }
+}
+
+/// Parses a decimal `f128`, which has no `FromStr` impl to defer to.
+///
+/// Keeps 34 significant digits and scales them by a power of ten,
+/// which is exact for short literals but otherwise only within a few ulps.
+#[cfg(feature = "f128")]
+fn f128_from_decimal(s: &str) -> Result<f128, ParseFloatError> {
+    const ERR: ParseFloatError = ParseFloatError::new();
+    let (negative, s) = split_sign(s);
+    let value = if s.eq_ignore_ascii_case("inf") || s.eq_ignore_ascii_case("infinity") {
+        f128::INFINITY
+    } else if s.eq_ignore_ascii_case("nan") {
+        f128::NAN
+    } else {
+        let (digits, mut exp) = match s.find(['e', 'E']) {
+            Some(i) => (&s[..i], parse_exp(&s[i + 1..]).ok_or(ERR)?),
+            None => (s, 0),
+        };
+        let (mut mantissa, mut any, mut point) = (0_u128, false, false);
+        for c in digits.chars() {
+            if c == '.' {
+                if point { return Err(ERR); }
+                point = true;
+                continue;
+            }
+            let d = u128::from(c.to_digit(10).ok_or(ERR)?);
+            any = true;
+            if mantissa < 10_u128.pow(33) {
+                mantissa = mantissa * 10 + d;
+                if point { exp = exp.saturating_sub(1); }
+            } else if !point {
+                exp = exp.saturating_add(1);
+            }
+        }
+        if !any { return Err(ERR); }
+        f128_scale10(mantissa as f128, exp)
+    };
+    Ok(if negative { -value } else { value })
+}
+
+/// Prints 34 significant digits, followed by zeros where the exact expansion continues.
+#[cfg(feature = "f128")]
+impl FormatFloat for f128 {
+    fn write_with_precision<W: fmt::Write>(self, w: &mut W, digits: usize) -> fmt::Result {
+        if self.is_nan() { return w.write_str("NaN"); }
+        if self.is_sign_negative() { w.write_char('-')?; }
+        let x = self.abs();
+        if x.is_infinite() { return w.write_str("inf"); }
+        // Rounds to `n * 10^-frac`, where `n` has at most 35 digits.
+        let k = if x == 0.0 { 0 } else { x.log10().floor() as i32 };
+        let frac = (33 - k).min(digits.min(i32::MAX as usize) as i32);
+        let n = f128_scale10(x, frac).round_ties_even() as u128;
+        if frac > 38 {
+            write!(w, "0.{:01$}", n, frac as usize)?;
+        } else if frac > 0 {
+            let p = 10_u128.pow(frac as u32);
+            write!(w, "{}.{:02$}", n / p, n % p, frac as usize)?;
+        } else {
+            write!(w, "{}", n)?;
+            for _ in 0..-frac { w.write_char('0')?; }
+            if digits > 0 { w.write_char('.')?; }
+        }
+        for _ in frac.max(0) as usize..digits { w.write_char('0')?; }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(<f64 as ParseFloat>::from_str("1.5e3"), Ok(1500.0));
+        assert_eq!(<f32 as ParseFloat>::from_str("-inf"), Ok(f32::NEG_INFINITY));
+        assert!(<f32 as ParseFloat>::from_str("NaN").unwrap().is_nan());
+        assert_eq!(<f64 as ParseFloat>::from_str("0x1.8p3"), Ok(12.0));
+        assert_eq!(<f64 as ParseFloat>::from_str("-0XAp-2"), Ok(-2.5));
+        assert_eq!(<f32 as ParseFloat>::from_str("0x.1"), Ok(0.0625));
+        assert_eq!(<f32 as ParseFloat>::from_str("+0xff"), Ok(255.0));
+        assert_eq!(<f64 as ParseFloat>::from_str("0x1p-1074"), Ok(f64::from_bits(1)));
+        assert_eq!(<f64 as ParseFloat>::from_str("0x1p-1075"), Ok(0.0));
+        assert_eq!(<f64 as ParseFloat>::from_str("0x1.8p-1074"), Ok(f64::from_bits(2)));
+        assert_eq!(<f64 as ParseFloat>::from_str("0x1.0000000000001p-1074"), Ok(f64::from_bits(1)));
+        assert_eq!(<f32 as ParseFloat>::from_str("0x1.fffffep127"), Ok(f32::MAX));
+        assert_eq!(<f32 as ParseFloat>::from_str("0x1p128"), Ok(f32::INFINITY));
+        assert_eq!(<f32 as ParseFloat>::from_str("0x1.000001p0"), Ok(1.0));
+        assert_eq!(<f32 as ParseFloat>::from_str("0x1.0000010000000000000001p0"),
+                   Ok(1.0 + f32::EPSILON));
+        assert_eq!(<f64 as ParseFloat>::from_str("0x0p99999999"), Ok(0.0));
+        for s in &["", "0x", "0xp1", "0x1p", "0x1.2.3", "0x1g", "1.2.3", "0x1p+-2"] {
+            assert!(<f64 as ParseFloat>::from_str(s).is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_format() {
+        struct Buf([u8; 16], usize);
+
+        impl fmt::Write for Buf {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.0[self.1..self.1 + s.len()].copy_from_slice(s.as_bytes());
+                self.1 += s.len();
+                Ok(())
+            }
+        }
+
+        let mut buf = Buf([0; 16], 0);
+        FormatFloat::write_with_precision(2.0_f32 / 3.0, &mut buf, 3).unwrap();
+        assert_eq!(&buf.0[..buf.1], b"0.667");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_to_string() {
+        assert_eq!(1.0_f64.to_string_with_precision(2), "1.00");
+        assert_eq!((-0.125_f32).to_string_with_precision(1), "-0.1");
+        assert_eq!(f64::INFINITY.to_string_with_precision(3), "inf");
+    }
+}
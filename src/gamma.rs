@@ -0,0 +1,64 @@
+//! Conversion between sRGB encoded and linear color components.
+//!
+//! Uses the exact piecewise curves of IEC 61966-2-1, not the `2.2` power approximation.
+//! Components are normalized to `[0, 1]`. Values below zero follow the linear segment,
+//! and values above one extend the power segment.
+
+use Float;
+
+/// Decodes an sRGB component to linear light.
+#[inline(always)]
+pub fn srgb_to_linear<T: Float>(c: T) -> T {
+    if c <= T::from_f64(0.04045) {
+        c / T::from_f64(12.92)
+    } else {
+        ((c + T::from_f64(0.055)) / T::from_f64(1.055)).powf(T::from_f64(2.4))
+    }
+}
+
+/// Encodes a linear light component as sRGB.
+#[inline(always)]
+pub fn linear_to_srgb<T: Float>(l: T) -> T {
+    if l <= T::from_f64(0.0031308) {
+        l * T::from_f64(12.92)
+    } else {
+        T::from_f64(1.055) * l.powf(T::from_f64(1.0 / 2.4)) - T::from_f64(0.055)
+    }
+}
+
+/// Returns the relative luminance of a linear color, with the Rec. 709 weights of sRGB.
+///
+/// Decode sRGB components with `srgb_to_linear` first.
+#[inline(always)]
+pub fn luminance<T: Float>(r: T, g: T, b: T) -> T {
+    T::from_f64(0.2126) * r + T::from_f64(0.7152) * g + T::from_f64(0.0722) * b
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ApproxEq;
+
+    #[test]
+    fn test_srgb() {
+        assert_eq!(srgb_to_linear(0.0_f32), 0.0);
+        assert_eq!(srgb_to_linear(1.0_f32), 1.0);
+        assert!(srgb_to_linear(0.5_f64).approx_eq(0.214_041_140_482_232_5, 1e-12));
+        assert!(linear_to_srgb(0.18_f64).approx_eq(0.461_356_129_500_441_9, 1e-12));
+        assert!(linear_to_srgb(1.0_f32).approx_eq(1.0, 1e-6));
+        // The segments meet at the threshold, up to the rounding of the published constants.
+        assert!(srgb_to_linear(0.040_450_001_f64).approx_eq(srgb_to_linear(0.04045), 1e-8));
+        assert!(linear_to_srgb(0.003_130_801_f64).approx_eq(linear_to_srgb(0.0031308), 1e-7));
+        for i in 0..=255 {
+            let c = i as f64 / 255.0;
+            assert!(linear_to_srgb(srgb_to_linear(c)).approx_eq(c, 1e-12), "{}", i);
+        }
+    }
+
+    #[test]
+    fn test_luminance() {
+        assert!(luminance(1.0_f64, 1.0, 1.0).approx_eq(1.0, 1e-12));
+        assert_eq!(luminance(0.0_f32, 0.0, 0.0), 0.0);
+        assert!(luminance(0.0_f64, 1.0, 0.0) > luminance(1.0, 0.0, 0.0));
+    }
+}
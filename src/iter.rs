@@ -0,0 +1,245 @@
+//! Iterator utilities.
+
+use {Float, NeumaierSum};
+
+/// Statistics over iterators of floats.
+pub trait FloatIterExt<T: Float>: Iterator<Item = T> + Sized {
+    /// Returns the sum of all items, or zero if empty.
+    fn sum_float(self) -> T { self.fold(T::zero(), |acc, x| acc + x) }
+
+    /// Returns the product of all items, or one if empty.
+    fn product_float(self) -> T { self.fold(T::one(), |acc, x| acc * x) }
+
+    /// Returns the arithmetic mean, or `None` if empty.
+    fn mean(self) -> Option<T> { welford(self).map(|(_, mean, _)| mean) }
+
+    /// Returns the population variance, or `None` if empty.
+    ///
+    /// Computed in a single pass with Welford's algorithm,
+    /// which avoids the cancellation of the naive sum of squares.
+    fn variance(self) -> Option<T> { welford(self).map(|(n, _, m2)| m2 / T::from_usize(n)) }
+
+    /// Returns the population standard deviation, or `None` if empty.
+    fn stddev(self) -> Option<T> { self.variance().map(|v| v.sqrt()) }
+
+    /// Returns the least item, ignoring NaN.
+    ///
+    /// Returns `None` if empty or if every item is NaN.
+    fn min_float(self) -> Option<T> {
+        self.filter(|x| !x.is_nan()).fold(None, |acc, x| match acc {
+            Some(m) if m <= x => Some(m),
+            _ => Some(x),
+        })
+    }
+
+    /// Returns the greatest item, ignoring NaN.
+    ///
+    /// Returns `None` if empty or if every item is NaN.
+    fn max_float(self) -> Option<T> {
+        self.filter(|x| !x.is_nan()).fold(None, |acc, x| match acc {
+            Some(m) if m >= x => Some(m),
+            _ => Some(x),
+        })
+    }
+}
+
+impl<T: Float, I: Iterator<Item = T>> FloatIterExt<T> for I {}
+
+/// Returns the count, mean and sum of squared deviations, or `None` if empty.
+fn welford<T: Float, I: Iterator<Item = T>>(iter: I) -> Option<(usize, T, T)> {
+    // Counted as an integer, since a float count stops growing at `2^24` for `f32`.
+    // The updates become too small to register in `mean` and `m2` over as many items,
+    // so both are accumulated with compensation.
+    let (mut n, mut mean, mut m2) = (0, NeumaierSum::new(), NeumaierSum::new());
+    for x in iter {
+        n += 1;
+        let delta = x - mean.sum();
+        mean.add(delta / T::from_usize(n));
+        m2.add(delta * (x - mean.sum()));
+    }
+    if n == 0 { None } else { Some((n, mean.sum(), m2.sum())) }
+}
+
+/// An iterator over evenly spaced floats.
+///
+/// Values are computed as `start + step * i` rather than by repeated addition,
+/// so rounding errors do not accumulate.
+#[derive(Clone, Debug)]
+pub struct FloatRange<T> {
+    start: T,
+    step: T,
+    // Returned exactly in place of the last computed value.
+    end: Option<T>,
+    len: usize,
+    front: usize,
+    back: usize,
+}
+
+impl<T: Float> FloatRange<T> {
+    fn new(start: T, step: T, end: Option<T>, len: usize) -> FloatRange<T> {
+        FloatRange { start, step, end, len, front: 0, back: len }
+    }
+
+    fn value(&self, i: usize) -> T {
+        match self.end {
+            Some(end) if i + 1 == self.len => end,
+            // The first value is `start` even for an infinite step.
+            _ if i == 0 => self.start,
+            _ => self.start + self.step * T::from_usize(i),
+        }
+    }
+}
+
+impl<T: Float> Iterator for FloatRange<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.front == self.back { return None; }
+        self.front += 1;
+        Some(self.value(self.front - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.back - self.front;
+        (n, Some(n))
+    }
+}
+
+impl<T: Float> DoubleEndedIterator for FloatRange<T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.front == self.back { return None; }
+        self.back -= 1;
+        Some(self.value(self.back))
+    }
+}
+
+impl<T: Float> ExactSizeIterator for FloatRange<T> {}
+
+/// Returns `n` evenly spaced values from `start` to `end`, both included.
+///
+/// The first and last values are exactly `start` and `end`.
+/// Returns just `start` when `n = 1`.
+pub fn linspace<T: Float>(start: T, end: T, n: usize) -> FloatRange<T> {
+    if n < 2 { return FloatRange::new(start, T::zero(), None, n); }
+    FloatRange::new(start, (end - start) / T::from_usize(n - 1), Some(end), n)
+}
+
+/// Returns `n` evenly spaced values from `start` towards `end`, excluding `end`.
+///
+/// Useful for periodic samples, such as angles around a circle.
+pub fn linspace_exclusive<T: Float>(start: T, end: T, n: usize) -> FloatRange<T> {
+    FloatRange::new(start, (end - start) / T::from_usize(n), None, n)
+}
+
+/// Returns the values `start + step * i` that are less than `end`,
+/// or greater than `end` for a negative step.
+///
+/// A value within rounding error of `end` counts as equal to it and is excluded.
+/// Returns an empty range if `step` is zero or NaN, or points away from `end`.
+/// The length saturates at `usize::MAX` when there are more values than that,
+/// as for a tiny step over a huge range.
+pub fn arange<T: Float>(start: T, end: T, step: T) -> FloatRange<T> {
+    let (len, hits_end) = arange_len(start, end, step);
+    FloatRange::new(start, step, None, if hits_end { len - 1 } else { len })
+}
+
+/// Like `arange`, but includes `end` when a value lands on it,
+/// in which case the last value is exactly `end`.
+pub fn arange_inclusive<T: Float>(start: T, end: T, step: T) -> FloatRange<T> {
+    let (len, hits_end) = arange_len(start, end, step);
+    FloatRange::new(start, step, if hits_end { Some(end) } else { None }, len)
+}
+
+/// Returns the number of values up to and including `end`,
+/// and whether the last one is within rounding error of `end`.
+fn arange_len<T: Float>(start: T, end: T, step: T) -> (usize, bool) {
+    let k = (end - start) / step;
+    if step == T::zero() || k.is_nan() || k < T::zero() { return (0, false); }
+    // Below this, the count and the value after it fit in `usize`.
+    if k >= T::from_usize(usize::MAX) { return (usize::MAX, false); }
+    let rounded = k.round();
+    // Whether the nearest value lands on `end`, up to a few ulps of the larger endpoint.
+    let last = if rounded == T::zero() { start } else { start + step * rounded };
+    let tolerance = end.abs().max(start.abs()) * T::epsilon() * T::from_f64(4.0);
+    if (last - end).abs() <= tolerance {
+        (rounded.to_usize() + 1, true)
+    } else {
+        (k.floor().to_usize() + 1, false)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let data = [2.0_f64, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        assert_eq!(data.iter().cloned().sum_float(), 40.0);
+        assert_eq!(data.iter().cloned().product_float(), 201600.0);
+        assert_eq!(data.iter().cloned().mean(), Some(5.0));
+        assert_eq!(data.iter().cloned().variance(), Some(4.0));
+        assert_eq!(data.iter().cloned().stddev(), Some(2.0));
+        assert_eq!(core::iter::empty::<f32>().mean(), None);
+        assert_eq!(core::iter::empty::<f32>().sum_float(), 0.0);
+        // Large offsets do not cancel.
+        let offset = data.iter().map(|x| x + 1e9);
+        assert!((offset.variance().unwrap() - 4.0).abs() < 1e-6);
+        // A large value followed by many small ones, whose updates mostly round away
+        // without compensation. Plain Welford is off by about `2e-5` in the variance.
+        let skewed = || core::iter::once(1e8_f32).chain(core::iter::repeat_n(1.0, 4095));
+        assert!((skewed().mean().unwrap() - 24_415.062).abs() < 4e-3);
+        assert!((skewed().variance().unwrap() / 2.440_810_2e12 - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let data = [f32::NAN, 3.0, -1.0, f32::NAN, 2.0];
+        assert_eq!(data.iter().cloned().min_float(), Some(-1.0));
+        assert_eq!(data.iter().cloned().max_float(), Some(3.0));
+        assert_eq!([f64::NAN].iter().cloned().max_float(), None);
+        assert_eq!(core::iter::empty::<f64>().min_float(), None);
+    }
+
+    #[test]
+    fn test_linspace() {
+        let v: [f64; 4] = {
+            let mut it = linspace(0.0, 1.0, 4);
+            [it.next().unwrap(), it.next().unwrap(), it.next().unwrap(), it.next().unwrap()]
+        };
+        assert_eq!(v, [0.0, 1.0 / 3.0, 2.0 / 3.0, 1.0]);
+        assert_eq!(linspace(0.1_f32, 0.7, 7).next_back(), Some(0.7));
+        assert_eq!(linspace(0.1_f32, 0.7, 7).len(), 7);
+        assert_eq!(linspace(2.0_f64, 3.0, 1).next_back(), Some(2.0));
+        assert_eq!(linspace(2.0_f64, 3.0, 0).next(), None);
+        assert_eq!(linspace_exclusive(0.0_f64, 1.0, 4).next_back(), Some(0.75));
+    }
+
+    #[test]
+    fn test_arange() {
+        assert_eq!(arange(0.0_f64, 1.0, 0.25).len(), 4);
+        assert_eq!(arange(0.0_f64, 1.0, 0.3).next_back(), Some(0.3 * 3.0));
+        assert_eq!(arange(0.0_f64, 0.3, 0.1).len(), 3);
+        assert_eq!(arange_inclusive(0.0_f64, 0.3, 0.1).len(), 4);
+        assert_eq!(arange_inclusive(0.0_f64, 0.3, 0.1).next_back(), Some(0.3));
+        assert_eq!(arange_inclusive(0.0_f32, 1.0, 0.3).next_back(), Some(0.3 * 3.0));
+        assert_eq!(arange(1.0_f64, 0.0, -0.5).sum_float(), 1.5);
+        assert_eq!(arange(0.0_f64, 1.0, -0.5).len(), 0);
+        assert_eq!(arange(0.0_f64, 1.0, 0.0).len(), 0);
+        assert_eq!(arange(0.0_f64, 1.0, f64::NAN).len(), 0);
+        assert_eq!(arange_inclusive(1.0_f32, 1.0, 0.5).len(), 1);
+        assert_eq!(arange(1.0_f32, 1.0, 0.5).len(), 0);
+        // A single value, however small the range is relative to the step.
+        let mut tiny = arange(0.0_f64, 1e-20, 1.0);
+        assert_eq!((tiny.next(), tiny.next()), (Some(0.0), None));
+        let mut infinite = arange(0.0_f64, 1.0, f64::INFINITY);
+        assert_eq!((infinite.next(), infinite.next()), (Some(0.0), None));
+        assert_eq!(arange_inclusive(0.0_f64, 1e-20, 1.0).len(), 1);
+        // Too many values to count.
+        assert_eq!(arange(0.0_f64, 1e30, 1.0).len(), usize::MAX);
+        assert_eq!(arange_inclusive(0.0_f32, 1e30, 1.0).len(), usize::MAX);
+        let mut huge = arange(0.0_f64, f64::MAX, 1e-300);
+        assert_eq!((huge.len(), huge.next(), huge.next()), (usize::MAX, Some(0.0), Some(1e-300)));
+        assert_eq!(arange(-f64::MAX, f64::MAX, 1.0).len(), usize::MAX);
+    }
+}
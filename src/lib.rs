@@ -1,14 +1,82 @@
 #![deny(missing_docs)]
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
+#![cfg_attr(feature = "f128", feature(f128))]
 
 //! Traits for generic floats in game programming
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
+
+#[cfg(feature = "std")]
+extern crate core;
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+compile_error!("either the `std` or the `libm` feature must be enabled");
+
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "deterministic", allow(dead_code))]
+mod math;
+#[cfg_attr(feature = "std", allow(dead_code))]
+mod libm;
+#[cfg(not(feature = "std"))]
+use libm as math;
+// Routines that must give identical results on every target.
+#[cfg(feature = "deterministic")]
+use libm as portable;
+#[cfg(not(feature = "deterministic"))]
+use math as portable;
+
+#[macro_use]
+mod newtype;
+#[cfg(feature = "simd")]
+mod simd;
+#[cfg(feature = "f128")]
+mod quad;
+mod angle;
+mod dual;
+mod dynamic;
+mod fenv;
+mod fmt;
+mod iter;
+mod ordered;
+mod random;
+mod special;
+mod sum;
+mod table;
+pub mod curve;
+pub mod easing;
+pub mod filter;
+pub mod gamma;
+pub mod noise;
+pub mod predicates;
+pub mod solve;
+
+pub use angle::{Deg, Rad};
+pub use dual::Dual;
+pub use dynamic::{AnyFloat, DynFloat};
+pub use fenv::{with_rounding_mode, DenormalsFlushGuard};
+pub use fmt::{FormatFloat, ParseFloat, ParseFloatError};
+pub use iter::{arange, arange_inclusive, linspace, linspace_exclusive, FloatIterExt, FloatRange};
+pub use ordered::{NotNan, Ordered};
+pub use random::RandomFloat;
+pub use special::Special;
+pub use sum::{KahanSum, NeumaierSum};
+pub use table::{SineTable, TableTrig};
+
+#[doc(hidden)]
+pub mod __core {
+    pub use core::{cmp, fmt, num, ops};
+}
+
+use core::cmp::Ordering;
+use core::num::FpCategory;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
 
 /// Convenience trait for floats.
 pub trait Float:
     'static + Send + Sync
-    + Copy + Radians + One + Zero + Sqrt
-    + FromPrimitive
-    + Min + Max + Signum + Powf
+    + Copy + Radians + Consts + One + Zero + Sqrt + Recip + Cbrt
+    + FromPrimitive + ToPrimitive + ToBytes + FromBytes + ParseFloat + FormatFloat
+    + Min + Max + Midpoint + Clamp + Signum + Abs + Powf + Powi + MulAdd
+    + Exp + Log + Round + EuclidOps + Classify + ApproxEq + TotalOrder
     + Trig
     + PartialEq
     + PartialOrd
@@ -22,9 +90,10 @@ pub trait Float:
 
 impl<T> Float for T where
     T: 'static + Send + Sync
-    + Copy + Radians + One + Zero + Sqrt
-    + FromPrimitive
-    + Min + Max + Signum + Powf
+    + Copy + Radians + Consts + One + Zero + Sqrt + Recip + Cbrt
+    + FromPrimitive + ToPrimitive + ToBytes + FromBytes + ParseFloat + FormatFloat
+    + Min + Max + Midpoint + Clamp + Signum + Abs + Powf + Powi + MulAdd
+    + Exp + Log + Round + EuclidOps + Classify + ApproxEq + TotalOrder
     + Trig
     + PartialEq
     + PartialOrd
@@ -68,368 +137,2525 @@ impl Max for f64 {
     fn max(self, other: Self) -> Self { self.max(other) }
 }
 
-/// The sign of the number.
-pub trait Signum {
-    /// Returns number representing the sign of self
-    fn signum(self) -> Self;
-}
+/// Minimum and maximum of several values, as when computing bounding boxes.
+///
+/// Follows the NaN handling of `Min` and `Max`, so for floats NaN is ignored unless every value is NaN.
+pub trait MinMax: Min + Max + Copy {
+    /// Returns the minimum of three values.
+    #[inline(always)]
+    fn min3(self, b: Self, c: Self) -> Self { self.min(b).min(c) }
 
-impl Signum for f32 {
+    /// Returns the maximum of three values.
     #[inline(always)]
-    fn signum(self) -> Self { self.signum() }
-}
+    fn max3(self, b: Self, c: Self) -> Self { self.max(b).max(c) }
 
-impl Signum for f64 {
+    /// Returns the minimum and maximum of self and other.
     #[inline(always)]
-    fn signum(self) -> Self { self.signum() }
+    fn min_max(self, other: Self) -> (Self, Self) { (self.min(other), self.max(other)) }
+
+    /// Returns the minimum of a slice, or `None` if empty.
+    #[inline(always)]
+    fn min_of(values: &[Self]) -> Option<Self> {
+        values.split_first().map(|(&first, rest)| rest.iter().fold(first, |acc, &x| acc.min(x)))
+    }
+
+    /// Returns the maximum of a slice, or `None` if empty.
+    #[inline(always)]
+    fn max_of(values: &[Self]) -> Option<Self> {
+        values.split_first().map(|(&first, rest)| rest.iter().fold(first, |acc, &x| acc.max(x)))
+    }
+
+    /// Returns the minimum and maximum of a slice in a single pass, or `None` if empty.
+    #[inline(always)]
+    fn min_max_of(values: &[Self]) -> Option<(Self, Self)> {
+        values.split_first().map(|(&first, rest)| {
+            rest.iter().fold((first, first), |(lo, hi), &x| (lo.min(x), hi.max(x)))
+        })
+    }
 }
 
-/// Floating number power.
-pub trait Powf {
-    /// Returns floating power of the number.
-    fn powf(self, other: Self) -> Self;
+impl<T: Min + Max + Copy> MinMax for T {}
+
+/// Average of two values.
+pub trait Midpoint {
+    /// Returns the value halfway between self and other.
+    ///
+    /// Does not overflow, and is correctly rounded for `f32` and `f64`.
+    fn midpoint(self, other: Self) -> Self;
 }
 
-impl Powf for f32 {
+impl Midpoint for f32 {
     #[inline(always)]
-    fn powf(self, other: Self) -> Self { self.powf(other) }
+    fn midpoint(self, other: f32) -> f32 { ((self as f64 + other as f64) / 2.0) as f32 }
 }
 
-impl Powf for f64 {
+impl Midpoint for f64 {
     #[inline(always)]
-    fn powf(self, other: Self) -> Self { self.powf(other) }
+    fn midpoint(self, other: f64) -> f64 {
+        const HI: f64 = f64::MAX / 2.0;
+        if self.abs() <= HI && other.abs() <= HI {
+            (self + other) / 2.0
+        } else {
+            // Halving first cannot underflow for such large operands.
+            self / 2.0 + other / 2.0
+        }
+    }
 }
 
-/// Useful constants for radians.
-pub trait Radians {
-    /// Returns radians corresponding to 90 degrees.
-    fn _90() -> Self;
+/// Restrict a value to a range.
+pub trait Clamp {
+    /// Returns self restricted to the range `[min, max]`.
+    ///
+    /// Returns NaN if self is NaN.
+    /// Panics if `min > max` or either bound is NaN, in every implementation.
+    /// `Dual` checks the bounds by value, ignoring their derivatives.
+    fn clamp(self, min: Self, max: Self) -> Self;
+    /// Returns self restricted to the range `[0, 1]`.
+    fn clamp01(self) -> Self;
+}
 
-    /// Returns radians corresponding to 180 degrees.
-    fn _180() -> Self;
+impl Clamp for f32 {
+    #[inline(always)]
+    fn clamp(self, min: Self, max: Self) -> Self { self.clamp(min, max) }
 
-    /// Returns radians corresponding to 360 degrees.
-    fn _360() -> Self;
+    #[inline(always)]
+    fn clamp01(self) -> Self { self.clamp(0.0, 1.0) }
+}
 
-    /// Convert a value to radians from degrees.
-    /// Equivalent to ```value * (π / 180)```.
-    fn deg_to_rad(self) -> Self;
+impl Clamp for f64 {
+    #[inline(always)]
+    fn clamp(self, min: Self, max: Self) -> Self { self.clamp(min, max) }
 
-    /// Convert a value to degrees from radians.
-    /// Equivalent to ```value * (180 / π)```.
-    fn rad_to_deg(self) -> Self;
+    #[inline(always)]
+    fn clamp01(self) -> Self { self.clamp(0.0, 1.0) }
 }
 
-impl Radians for f32 {
-    #[inline(always)]
-    fn _90() -> f32 {
-        ::std::f32::consts::FRAC_PI_2
-    }
+/// Range mapping, snapping and bucketing, for sliders, meters and grids.
+pub trait RangeOps: Sized {
+    /// Maps self linearly from the range `from` to the range `to`,
+    /// clamping the result to `to` if `clamp` is true.
+    fn map_range(self, from: (Self, Self), to: (Self, Self), clamp: bool) -> Self;
+    /// Returns 0 if self is less than `edge`, and 1 otherwise.
+    fn step(self, edge: Self) -> Self;
+    /// Snaps self to the nearest multiple of `step`, with ties away from zero.
+    ///
+    /// Returns self unchanged if `step` is zero.
+    fn quantize(self, step: Self) -> Self;
+    /// Returns the index of the bucket containing self,
+    /// when `[min, max]` is split into `count` buckets of equal width.
+    ///
+    /// `max` falls into the last bucket. Returns `None` for NaN,
+    /// values outside the range and zero buckets.
+    fn bucket(self, min: Self, max: Self, count: usize) -> Option<usize>;
+}
 
+impl<T: Float> RangeOps for T {
     #[inline(always)]
-    fn _180() -> f32 {
-        ::std::f32::consts::PI
+    fn map_range(self, from: (T, T), to: (T, T), clamp: bool) -> T {
+        let t = from.0.inverse_lerp(from.1, self);
+        to.0.lerp(to.1, if clamp { t.clamp01() } else { t })
     }
 
     #[inline(always)]
-    fn _360() -> f32 {
-        <Self as Radians>::_180() * 2.0
-    }
+    fn step(self, edge: T) -> T { if self < edge { T::zero() } else { T::one() } }
 
     #[inline(always)]
-    fn deg_to_rad(self) -> Self {
-        self * (::std::f32::consts::PI / 180.0_f32)
+    fn quantize(self, step: T) -> T {
+        if step == T::zero() { self } else { (self / step).round() * step }
     }
 
     #[inline(always)]
-    fn rad_to_deg(self) -> Self {
-        self * (180.0_f32 / ::std::f32::consts::PI)
+    fn bucket(self, min: T, max: T, count: usize) -> Option<usize> {
+        if count == 0 || !(self >= min && self <= max) { return None; }
+        let i = (min.inverse_lerp(max, self) * T::from_usize(count)).floor().to_usize();
+        Some(i.min(count - 1))
     }
 }
 
-impl Radians for f64 {
-    #[inline(always)]
-    fn _90() -> f64 {
-        ::std::f64::consts::FRAC_PI_2
-    }
+/// The sign of the number.
+pub trait Signum {
+    /// Returns number representing the sign of self
+    fn signum(self) -> Self;
+}
 
+impl Signum for f32 {
     #[inline(always)]
-    fn _180() -> f64 {
-        ::std::f64::consts::PI
-    }
+    fn signum(self) -> Self { self.signum() }
+}
 
+impl Signum for f64 {
     #[inline(always)]
-    fn _360() -> f64 {
-        <Self as Radians>::_180() * 2.0
+    fn signum(self) -> Self { self.signum() }
+}
+
+/// The sign of a value, with zero and NaN told apart.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Sign {
+    /// Less than zero.
+    Negative,
+    /// Positive or negative zero.
+    Zero,
+    /// Greater than zero.
+    Positive,
+    /// Not a number.
+    Nan,
+}
+
+/// Three-way sign classification.
+pub trait Signed: Copy {
+    /// Returns the sign of self.
+    ///
+    /// Unlike `signum`, both zeros give `Sign::Zero` and NaN gives `Sign::Nan`.
+    fn sign(self) -> Sign;
+    /// Returns true if the sign bit is clear, including for `0.0` and positive NaN.
+    fn is_sign_positive(self) -> bool;
+    /// Returns true if the sign bit is set, including for `-0.0` and negative NaN.
+    fn is_sign_negative(self) -> bool;
+}
+
+impl<T: Float> Signed for T {
+    #[inline(always)]
+    fn sign(self) -> Sign {
+        if self.is_nan() {
+            Sign::Nan
+        } else if self == T::zero() {
+            Sign::Zero
+        } else if self < T::zero() {
+            Sign::Negative
+        } else {
+            Sign::Positive
+        }
     }
 
     #[inline(always)]
-    fn deg_to_rad(self) -> Self {
-        self * (::std::f64::consts::PI / 180.0_f64)
-    }
+    fn is_sign_positive(self) -> bool { !self.is_sign_negative() }
 
+    // `copysign` transfers the sign bit even from zeros and NaNs.
     #[inline(always)]
-    fn rad_to_deg(self) -> Self {
-        self * (180.0_f64 / ::std::f64::consts::PI)
-    }
+    fn is_sign_negative(self) -> bool { T::one().copysign(self) < T::zero() }
 }
 
-/// Number 1.
-pub trait One {
-    /// Returns 1.
-    fn one() -> Self;
+/// Absolute value and related functions.
+pub trait Abs {
+    /// Returns the absolute value of self.
+    fn abs(self) -> Self;
+    /// Returns a number with the magnitude of self and the sign of `sign`.
+    fn copysign(self, sign: Self) -> Self;
+    /// Returns the length of the hypotenuse of a right-angle triangle
+    /// with legs of length self and other, without undue overflow or underflow.
+    fn hypot(self, other: Self) -> Self;
 }
 
-/// Number 0.
-pub trait Zero {
-    /// Returns 0.
-    fn zero() -> Self;
-}
+impl Abs for f32 {
+    #[inline(always)]
+    fn abs(self) -> Self { self.abs() }
 
-impl One for f32 {
     #[inline(always)]
-    fn one() -> f32 { 1.0 }
-}
+    fn copysign(self, sign: Self) -> Self { self.copysign(sign) }
 
-impl One for f64 {
     #[inline(always)]
-    fn one() -> f64 { 1.0 }
+    fn hypot(self, other: Self) -> Self { math::hypotf(self, other) }
 }
 
-impl Zero for f32 {
+impl Abs for f64 {
     #[inline(always)]
-    fn zero() -> f32 { 0.0 }
-}
+    fn abs(self) -> Self { self.abs() }
 
-impl Zero for f64 {
     #[inline(always)]
-    fn zero() -> f64 { 0.0 }
+    fn copysign(self, sign: Self) -> Self { self.copysign(sign) }
+
+    #[inline(always)]
+    fn hypot(self, other: Self) -> Self { math::hypot(self, other) }
 }
 
-/// Square root.
-pub trait Sqrt {
-    /// Returns square root.
-    fn sqrt(self) -> Self;
+/// Floating number power.
+pub trait Powf {
+    /// Returns floating power of the number.
+    fn powf(self, other: Self) -> Self;
 }
 
-impl Sqrt for f32 {
+impl Powf for f32 {
     #[inline(always)]
-    fn sqrt(self) -> f32 { self.sqrt() }
+    fn powf(self, other: Self) -> Self { portable::powf(self, other) }
 }
 
-impl Sqrt for f64 {
+impl Powf for f64 {
     #[inline(always)]
-    fn sqrt(self) -> f64 { self.sqrt() }
+    fn powf(self, other: Self) -> Self { portable::pow(self, other) }
 }
 
-/// Basic trigonometry functions
-pub trait Trig {
-    /// Returns sine of self.
-    fn sin(self) -> Self;
-    /// Returns cosine of self.
-    fn cos(self) -> Self;
-    /// Returns tangent of self.
-    fn tan(self) -> Self;
-    /// Returns inverse sine of self.
-    fn asin(self) -> Self;
-    /// Returns inverse cosine of self.
-    fn acos(self) -> Self;
-    /// Returns inverse tangent of self.
-    fn atan(self) -> Self;
-    /// Returns the four quadrant arctangent of self (y) and other (x).
-    fn atan2(self, other: Self) -> Self;
-    /// Returns hyperbolic sine of self.
-    fn sinh(self) -> Self;
-    /// Returns hyperbolic cosine of self.
-    fn cosh(self) -> Self;
-    /// Returns hyperbolic tangent of self.
-    fn tanh(self) -> Self;
-    /// Returns inverse hyperbolic sine of self.
-    fn asinh(self) -> Self;
-    /// Returns inverse hyperbolic cosine of self.
-    fn acosh(self) -> Self;
-    /// Returns inverse hyperbolic tangent of self.
-    fn atanh(self) -> Self;
+/// Integer number power.
+pub trait Powi {
+    /// Returns integer power of the number.
+    ///
+    /// Faster than `powf`, but the precision may vary with the exponent and platform.
+    fn powi(self, n: i32) -> Self;
+    /// Returns the square of the number.
+    fn sqr(self) -> Self;
+    /// Returns the cube of the number.
+    fn cube(self) -> Self;
 }
 
-impl Trig for f32 {
+impl Powi for f32 {
     #[inline(always)]
-    fn sin(self) -> f32 { self.sin() }
+    fn powi(self, n: i32) -> Self { math::powif(self, n) }
 
     #[inline(always)]
-    fn cos(self) -> f32 { self.cos() }
+    fn sqr(self) -> Self { self * self }
 
     #[inline(always)]
-    fn tan(self) -> f32 { self.tan() }
+    fn cube(self) -> Self { self * self * self }
+}
 
+impl Powi for f64 {
     #[inline(always)]
-    fn asin(self) -> f32 { self.asin() }
+    fn powi(self, n: i32) -> Self { math::powi(self, n) }
 
     #[inline(always)]
-    fn acos(self) -> f32 { self.acos() }
+    fn sqr(self) -> Self { self * self }
 
     #[inline(always)]
-    fn atan(self) -> f32 { self.atan() }
+    fn cube(self) -> Self { self * self * self }
+}
 
-    #[inline(always)]
-    fn atan2(self, other: f32) -> f32 { self.atan2(other) }
+/// Fused multiply-add.
+pub trait MulAdd {
+    /// Returns `(self * a) + b` with only one rounding error.
+    ///
+    /// Uses the hardware instruction where available,
+    /// which is both faster and more precise than a separate multiply and add.
+    fn mul_add(self, a: Self, b: Self) -> Self;
+}
 
+impl MulAdd for f32 {
     #[inline(always)]
-    fn sinh(self) -> f32 { self.sinh() }
+    fn mul_add(self, a: Self, b: Self) -> Self { math::fmaf(self, a, b) }
+}
 
+impl MulAdd for f64 {
     #[inline(always)]
-    fn cosh(self) -> f32 { self.cosh() }
+    fn mul_add(self, a: Self, b: Self) -> Self { math::fma(self, a, b) }
+}
 
-    #[inline(always)]
-    fn tanh(self) -> f32 { self.tanh() }
+/// Exponential functions.
+pub trait Exp {
+    /// Returns `e^(self)`.
+    fn exp(self) -> Self;
+    /// Returns `2^(self)`.
+    fn exp2(self) -> Self;
+    /// Returns `e^(self) - 1` in a way that is accurate even if the number is close to zero.
+    fn exp_m1(self) -> Self;
+}
 
+impl Exp for f32 {
     #[inline(always)]
-    fn asinh(self) -> f32 { self.asinh() }
+    fn exp(self) -> f32 { math::expf(self) }
 
     #[inline(always)]
-    fn acosh(self) -> f32 { self.acosh() }
+    fn exp2(self) -> f32 { math::exp2f(self) }
 
     #[inline(always)]
-    fn atanh(self) -> f32 { self.atanh() }
+    fn exp_m1(self) -> f32 { math::expm1f(self) }
 }
 
-impl Trig for f64 {
+impl Exp for f64 {
     #[inline(always)]
-    fn sin(self) -> f64 { self.sin() }
+    fn exp(self) -> f64 { math::exp(self) }
 
     #[inline(always)]
-    fn cos(self) -> f64 { self.cos() }
+    fn exp2(self) -> f64 { math::exp2(self) }
 
     #[inline(always)]
-    fn tan(self) -> f64 { self.tan() }
+    fn exp_m1(self) -> f64 { math::expm1(self) }
+}
+
+/// Logarithm functions.
+pub trait Log {
+    /// Returns the natural logarithm of self.
+    fn ln(self) -> Self;
+    /// Returns the logarithm of self with respect to an arbitrary base.
+    fn log(self, base: Self) -> Self;
+    /// Returns the base 2 logarithm of self.
+    fn log2(self) -> Self;
+    /// Returns the base 10 logarithm of self.
+    fn log10(self) -> Self;
+    /// Returns `ln(1 + self)` more accurately than if the operations were performed separately.
+    fn ln_1p(self) -> Self;
+}
 
+impl Log for f32 {
     #[inline(always)]
-    fn asin(self) -> f64 { self.asin() }
+    fn ln(self) -> f32 { math::logf(self) }
 
     #[inline(always)]
-    fn acos(self) -> f64 { self.acos() }
+    fn log(self, base: f32) -> f32 { math::logf(self) / math::logf(base) }
 
     #[inline(always)]
-    fn atan(self) -> f64 { self.atan() }
+    fn log2(self) -> f32 { math::log2f(self) }
 
     #[inline(always)]
-    fn atan2(self, other: f64) -> f64 { self.atan2(other) }
+    fn log10(self) -> f32 { math::log10f(self) }
 
     #[inline(always)]
-    fn sinh(self) -> f64 { self.sinh() }
+    fn ln_1p(self) -> f32 { math::log1pf(self) }
+}
 
+impl Log for f64 {
     #[inline(always)]
-    fn cosh(self) -> f64 { self.cosh() }
+    fn ln(self) -> f64 { math::log(self) }
 
     #[inline(always)]
-    fn tanh(self) -> f64 { self.tanh() }
+    fn log(self, base: f64) -> f64 { math::log(self) / math::log(base) }
 
     #[inline(always)]
-    fn asinh(self) -> f64 { self.asinh() }
+    fn log2(self) -> f64 { math::log2(self) }
 
     #[inline(always)]
-    fn acosh(self) -> f64 { self.acosh() }
+    fn log10(self) -> f64 { math::log10(self) }
 
     #[inline(always)]
-    fn atanh(self) -> f64 { self.atanh() }
+    fn ln_1p(self) -> f64 { math::log1p(self) }
 }
 
-/// Casts into another type.
-pub trait Cast<T> {
-    /// Casts into other type.
-    fn cast(self) -> T;
+/// Rounding functions.
+pub trait Round {
+    /// Returns the largest integer less than or equal to self.
+    fn floor(self) -> Self;
+    /// Returns the smallest integer greater than or equal to self.
+    fn ceil(self) -> Self;
+    /// Returns the nearest integer to self, rounding half-way cases away from zero.
+    fn round(self) -> Self;
+    /// Returns the integer part of self, rounding towards zero.
+    fn trunc(self) -> Self;
+    /// Returns the fractional part of self, equivalent to `self - self.trunc()`.
+    fn fract(self) -> Self;
 }
 
-impl Cast<f32> for f64 {
+impl Round for f32 {
     #[inline(always)]
-    fn cast(self) -> f32 { self as f32 }
-}
+    fn floor(self) -> f32 { math::floorf(self) }
 
-impl Cast<f64> for f32 {
     #[inline(always)]
-    fn cast(self) -> f64 { self as f64 }
-}
+    fn ceil(self) -> f32 { math::ceilf(self) }
 
-impl Cast<f32> for f32 {
     #[inline(always)]
-    fn cast(self) -> f32 { self }
+    fn round(self) -> f32 { math::roundf(self) }
+
+    #[inline(always)]
+    fn trunc(self) -> f32 { math::truncf(self) }
+
+    #[inline(always)]
+    fn fract(self) -> f32 { math::fractf(self) }
 }
 
-impl Cast<f64> for f64 {
+impl Round for f64 {
     #[inline(always)]
-    fn cast(self) -> f64 { self }
+    fn floor(self) -> f64 { math::floor(self) }
+
+    #[inline(always)]
+    fn ceil(self) -> f64 { math::ceil(self) }
+
+    #[inline(always)]
+    fn round(self) -> f64 { math::round(self) }
+
+    #[inline(always)]
+    fn trunc(self) -> f64 { math::trunc(self) }
+
+    #[inline(always)]
+    fn fract(self) -> f64 { math::fract(self) }
 }
 
-/// Trait for converting from different numeric types
-pub trait FromPrimitive {
-    /// from a f64
-    fn from_f64(t: f64) -> Self;
-    /// from a f32
-    fn from_f32(t: f32) -> Self;
-    /// from a isze
-    fn from_isize(t: isize) -> Self;
-    /// from a u32
-    fn from_u32(t: u32) -> Self;
-    /// from a i32
-    fn from_i32(t: i32) -> Self;
-    // Add more as needed..
+/// Euclidean division and remainder.
+pub trait EuclidOps {
+    /// Returns the quotient of Euclidean division,
+    /// such that `self = rhs * self.div_euclid(rhs) + self.rem_euclid(rhs)`.
+    fn div_euclid(self, rhs: Self) -> Self;
+    /// Returns the least non-negative remainder of `self (mod rhs)`.
+    fn rem_euclid(self, rhs: Self) -> Self;
 }
 
-impl FromPrimitive for f64 {
+macro_rules! euclid_impl {
+    ($t:ident, $trunc:path) => {
+        impl EuclidOps for $t {
+            #[inline(always)]
+            fn div_euclid(self, rhs: $t) -> $t {
+                let q = $trunc(self / rhs);
+                if self % rhs < 0.0 {
+                    if rhs > 0.0 { q - 1.0 } else { q + 1.0 }
+                } else {
+                    q
+                }
+            }
+
+            #[inline(always)]
+            fn rem_euclid(self, rhs: $t) -> $t {
+                let r = self % rhs;
+                if r < 0.0 { r + rhs.abs() } else { r }
+            }
+        }
+    };
+}
+
+euclid_impl!(f32, math::truncf);
+euclid_impl!(f64, math::trunc);
+#[cfg(feature = "f128")]
+euclid_impl!(f128, f128::trunc);
+
+/// Floating point classification.
+pub trait Classify: Copy {
+    /// Returns `true` if self is NaN.
+    fn is_nan(self) -> bool;
+    /// Returns `true` if self is positive or negative infinity.
+    fn is_infinite(self) -> bool;
+    /// Returns `true` if self is neither infinite nor NaN.
+    fn is_finite(self) -> bool;
+    /// Returns `true` if self is neither zero, infinite, subnormal nor NaN.
+    fn is_normal(self) -> bool;
+    /// Returns the floating point category of self.
+    fn classify(self) -> FpCategory;
+}
+
+impl Classify for f32 {
     #[inline(always)]
-    fn from_f64(t: f64) -> Self { t }
+    fn is_nan(self) -> bool { self.is_nan() }
+
     #[inline(always)]
-    fn from_f32(t: f32) -> Self { t as f64 }
+    fn is_infinite(self) -> bool { self.is_infinite() }
+
     #[inline(always)]
-    fn from_isize(t: isize) -> Self { t as f64 }
+    fn is_finite(self) -> bool { self.is_finite() }
+
     #[inline(always)]
-    fn from_u32(t: u32) -> Self { t as f64 }
+    fn is_normal(self) -> bool { self.is_normal() }
+
     #[inline(always)]
-    fn from_i32(t: i32) -> Self { t as f64 }
+    fn classify(self) -> FpCategory { self.classify() }
 }
 
-impl FromPrimitive for f32 {
+impl Classify for f64 {
     #[inline(always)]
-    fn from_f64(t: f64) -> Self { t as f32 }
+    fn is_nan(self) -> bool { self.is_nan() }
+
     #[inline(always)]
-    fn from_f32(t: f32) -> Self { t }
+    fn is_infinite(self) -> bool { self.is_infinite() }
+
     #[inline(always)]
-    fn from_isize(t: isize) -> Self { t as f32 }
+    fn is_finite(self) -> bool { self.is_finite() }
+
     #[inline(always)]
-    fn from_u32(t: u32) -> Self { t as f32 }
+    fn is_normal(self) -> bool { self.is_normal() }
+
     #[inline(always)]
-    fn from_i32(t: i32) -> Self { t as f32 }
+    fn classify(self) -> FpCategory { self.classify() }
 }
 
-#[cfg(test)]
-mod test {
-    use super::*;
+/// Approximate equality.
+pub trait ApproxEq: Copy {
+    /// Returns `true` if self and other differ by at most `epsilon`.
+    fn approx_eq(self, other: Self, epsilon: Self) -> bool;
+    /// Returns `true` if self and other are at most `max_ulps`
+    /// representable numbers apart.
+    ///
+    /// NaN is never equal to anything, and numbers of different sign
+    /// are only equal if both are zero.
+    fn ulps_eq(self, other: Self, max_ulps: u32) -> bool;
+}
 
-    #[test]
-    fn test_f32_sqrt() {
-        let a = 4.0_f32;
-        let b = <f32 as Sqrt>::sqrt(a);
-        assert!((b - 2.0_f32).abs() < f32::EPSILON)
+impl ApproxEq for f32 {
+    #[inline(always)]
+    fn approx_eq(self, other: f32, epsilon: f32) -> bool {
+        self == other || (self - other).abs() <= epsilon
     }
 
-    #[test]
-    fn test_f64_sqrt() {
-        let a = 4.0_f64;
-        let b = <f64 as Sqrt>::sqrt(a);
-        assert!((b - 2.0_f64).abs() < f64::EPSILON)
+    #[inline(always)]
+    fn ulps_eq(self, other: f32, max_ulps: u32) -> bool {
+        if self == other { return true; }
+        if self.is_nan() || other.is_nan() || self.is_sign_negative() != other.is_sign_negative() {
+            return false;
+        }
+        let (a, b) = (self.to_bits(), other.to_bits());
+        a.abs_diff(b) <= max_ulps
     }
+}
 
-    #[test]
-    fn test_f32_deg_to_rad() {
-        let degrees = 23.0_f32;
-        let radians = degrees.deg_to_rad();
-        assert!((radians - 0.401_425).abs() > f32::EPSILON);
+impl ApproxEq for f64 {
+    #[inline(always)]
+    fn approx_eq(self, other: f64, epsilon: f64) -> bool {
+        self == other || (self - other).abs() <= epsilon
     }
 
-    #[test]
-    fn test_f64_deg_to_rad() {
-        let degrees = 60.0_f64;
-        let radians = degrees.deg_to_rad();
-        assert!((radians - std::f64::consts::FRAC_PI_3).abs()  == f64::EPSILON);
+    #[inline(always)]
+    fn ulps_eq(self, other: f64, max_ulps: u32) -> bool {
+        if self == other { return true; }
+        if self.is_nan() || other.is_nan() || self.is_sign_negative() != other.is_sign_negative() {
+            return false;
+        }
+        let (a, b) = (self.to_bits(), other.to_bits());
+        a.abs_diff(b) <= max_ulps as u64
+    }
+}
+
+/// Asserts that two floats are approximately equal.
+///
+/// Uses `Consts::epsilon()` unless an explicit epsilon is given.
+/// Either way the epsilon is an absolute bound, so the default only suits values
+/// of magnitude around 1, and larger values need an epsilon scaled to them.
+///
+/// ```
+/// #[macro_use]
+/// extern crate float;
+///
+/// # fn main() {
+/// assert_approx_eq!(0.1_f64 + 0.2, 0.3);
+/// assert_approx_eq!(1.0_f32, 1.01, 0.1);
+/// // `1000.1 + 0.2` is off by far more than `f64::EPSILON`.
+/// assert_approx_eq!(1000.1_f64 + 0.2, 1000.3, 1e-12);
+/// // Also callable by path, without `#[macro_use]`.
+/// float::assert_approx_eq!(0.5_f64, 0.5);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_approx_eq {
+    ($a:expr, $b:expr) => {
+        $crate::assert_approx_eq!($a, $b, $crate::Consts::epsilon())
+    };
+    ($a:expr, $b:expr, $epsilon:expr) => {
+        match ($a, $b, $epsilon) {
+            (a, b, epsilon) => {
+                if !$crate::ApproxEq::approx_eq(a, b, epsilon) {
+                    panic!(
+                        "assertion failed: `(left ≈ right)`\n  left: `{:?}`,\n right: `{:?}`,\n   eps: `{:?}`",
+                        a, b, epsilon
+                    );
+                }
+            }
+        }
+    };
+}
+
+/// IEEE 754 total ordering.
+///
+/// Orders `-NaN < -inf < ... < -0.0 < 0.0 < ... < inf < NaN`,
+/// so sorting never panics or gives inconsistent results.
+pub trait TotalOrder: Copy {
+    /// Compares self and other in total order.
+    fn total_cmp(self, other: Self) -> Ordering;
+
+    /// Returns the greater of self and other in total order.
+    #[inline(always)]
+    fn max_by_total(self, other: Self) -> Self {
+        if self.total_cmp(other) == Ordering::Greater { self } else { other }
+    }
+
+    /// Returns the lesser of self and other in total order.
+    #[inline(always)]
+    fn min_by_total(self, other: Self) -> Self {
+        if self.total_cmp(other) == Ordering::Greater { other } else { self }
+    }
+}
+
+impl TotalOrder for f32 {
+    #[inline(always)]
+    fn total_cmp(self, other: f32) -> Ordering { f32::total_cmp(&self, &other) }
+}
+
+impl TotalOrder for f64 {
+    #[inline(always)]
+    fn total_cmp(self, other: f64) -> Ordering { f64::total_cmp(&self, &other) }
+}
+
+/// Access to the raw bit pattern.
+pub trait Bits: Copy {
+    /// The unsigned integer type with the same width.
+    type Bits;
+
+    /// Returns the raw bit pattern.
+    fn to_bits(self) -> Self::Bits;
+    /// Creates a float from a raw bit pattern.
+    fn from_bits(bits: Self::Bits) -> Self;
+    /// Returns the least number greater than self.
+    ///
+    /// Returns self for NaN and positive infinity.
+    fn next_up(self) -> Self;
+    /// Returns the greatest number less than self.
+    ///
+    /// Returns self for NaN and negative infinity.
+    fn next_down(self) -> Self;
+    /// Returns the distance from the absolute value of self
+    /// to the next number of larger magnitude.
+    ///
+    /// Returns NaN for NaN and infinity for infinities.
+    fn ulp(self) -> Self;
+}
+
+macro_rules! bits_impl {
+    ($t:ident, $bits:ident) => {
+        impl Bits for $t {
+            type Bits = $bits;
+
+            #[inline(always)]
+            fn to_bits(self) -> $bits { self.to_bits() }
+
+            #[inline(always)]
+            fn from_bits(bits: $bits) -> $t { $t::from_bits(bits) }
+
+            #[inline(always)]
+            fn next_up(self) -> $t {
+                if self.is_nan() || self == $t::INFINITY { return self; }
+                if self == 0.0 { return $t::from_bits(1); }
+                let bits = self.to_bits();
+                $t::from_bits(if self > 0.0 { bits + 1 } else { bits - 1 })
+            }
+
+            #[inline(always)]
+            fn next_down(self) -> $t { -Bits::next_up(-self) }
+
+            #[inline(always)]
+            fn ulp(self) -> $t {
+                let a = self.abs();
+                if !a.is_finite() { return a; }
+                if a == $t::MAX { a - Bits::next_down(a) } else { Bits::next_up(a) - a }
+            }
+        }
+    };
+}
+
+bits_impl!(f32, u32);
+bits_impl!(f64, u64);
+#[cfg(feature = "f128")]
+bits_impl!(f128, u128);
+
+/// Conversion to a byte array.
+pub trait ToBytes: Copy {
+    /// The byte array type, `[u8; 4]` for `f32` and `[u8; 8]` for `f64`.
+    type Bytes: Copy + Default + AsRef<[u8]> + AsMut<[u8]>;
+
+    /// Returns the bit pattern as bytes in little-endian order.
+    fn to_le_bytes(self) -> Self::Bytes;
+    /// Returns the bit pattern as bytes in big-endian order.
+    fn to_be_bytes(self) -> Self::Bytes;
+}
+
+/// Conversion from a byte array.
+pub trait FromBytes: ToBytes {
+    /// Creates a float from its bytes in little-endian order.
+    fn from_le_bytes(bytes: Self::Bytes) -> Self;
+    /// Creates a float from its bytes in big-endian order.
+    fn from_be_bytes(bytes: Self::Bytes) -> Self;
+}
+
+macro_rules! bytes_impl {
+    ($t:ident, $n:expr) => {
+        impl ToBytes for $t {
+            type Bytes = [u8; $n];
+
+            #[inline(always)]
+            fn to_le_bytes(self) -> [u8; $n] { self.to_le_bytes() }
+
+            #[inline(always)]
+            fn to_be_bytes(self) -> [u8; $n] { self.to_be_bytes() }
+        }
+
+        impl FromBytes for $t {
+            #[inline(always)]
+            fn from_le_bytes(bytes: [u8; $n]) -> $t { $t::from_le_bytes(bytes) }
+
+            #[inline(always)]
+            fn from_be_bytes(bytes: [u8; $n]) -> $t { $t::from_be_bytes(bytes) }
+        }
+    };
+}
+
+bytes_impl!(f32, 4);
+bytes_impl!(f64, 8);
+#[cfg(feature = "f128")]
+bytes_impl!(f128, 16);
+
+/// Decomposition and scaling by powers of two.
+///
+/// Exact wherever the result is representable, and cheaper than `Powf`.
+/// The float versions of `exp2` and `log2` are in `Exp` and `Log`.
+pub trait Scalb: Sized {
+    /// Splits self into a mantissa in `[0.5, 1)` and an exponent,
+    /// such that `self = mantissa * 2^exponent`.
+    ///
+    /// Returns self and `0` for zero, infinities and NaN.
+    fn frexp(self) -> (Self, i32);
+    /// Returns `self * 2^exp`.
+    fn ldexp(self, exp: i32) -> Self;
+    /// Returns `2^n`.
+    fn exp2i(n: i32) -> Self;
+    /// Returns the exponent `e` such that `2^e <= |self| < 2^(e + 1)`.
+    ///
+    /// Returns `i32::MIN` for zero and NaN and `i32::MAX` for infinities.
+    fn log2i(self) -> i32;
+}
+
+macro_rules! scalb_impl {
+    ($t:ident, $bits:ident, $mantissa:expr, $bias:expr) => {
+        impl Scalb for $t {
+            #[inline(always)]
+            fn frexp(self) -> ($t, i32) {
+                if self == 0.0 || !self.is_finite() { return (self, 0); }
+                // Subnormals are scaled into the normal range first.
+                let (x, offset) = if self.is_normal() {
+                    (self, 0)
+                } else {
+                    (self * <$t as Scalb>::exp2i($mantissa + 1), -$mantissa - 1)
+                };
+                let bits = x.to_bits();
+                let mask = (2 * $bias + 1) as $bits;
+                let exp = ((bits >> $mantissa) & mask) as i32 - ($bias - 1);
+                let mantissa = (bits & !(mask << $mantissa)) | (($bias - 1) as $bits) << $mantissa;
+                ($t::from_bits(mantissa), exp + offset)
+            }
+
+            #[inline(always)]
+            fn ldexp(self, exp: i32) -> $t {
+                // Applied in up to three steps, so that the scale factors stay representable
+                // and a subnormal result is rounded only once.
+                let (min, step) = (1 - $bias, 1 - $bias + $mantissa + 1);
+                let (mut y, mut n) = (self, exp);
+                if n > $bias {
+                    y *= <$t as Scalb>::exp2i($bias);
+                    n -= $bias;
+                    if n > $bias {
+                        y *= <$t as Scalb>::exp2i($bias);
+                        n -= $bias;
+                        if n > $bias { n = $bias; }
+                    }
+                } else if n < min {
+                    y *= <$t as Scalb>::exp2i(step);
+                    n -= step;
+                    if n < min {
+                        y *= <$t as Scalb>::exp2i(step);
+                        n -= step;
+                        if n < min { n = min; }
+                    }
+                }
+                y * <$t as Scalb>::exp2i(n)
+            }
+
+            #[inline(always)]
+            fn exp2i(n: i32) -> $t {
+                if n > $bias {
+                    $t::INFINITY
+                } else if n >= 1 - $bias {
+                    $t::from_bits((($bias + n) as $bits) << $mantissa)
+                } else if n >= 1 - $bias - $mantissa {
+                    $t::from_bits(1 << (n - (1 - $bias - $mantissa)))
+                } else {
+                    0.0
+                }
+            }
+
+            #[inline(always)]
+            fn log2i(self) -> i32 {
+                if self.is_nan() || self == 0.0 { return i32::MIN; }
+                if self.is_infinite() { return i32::MAX; }
+                Scalb::frexp(self).1 - 1
+            }
+        }
+    };
+}
+
+scalb_impl!(f32, u32, 23, 127);
+scalb_impl!(f64, u64, 52, 1023);
+#[cfg(feature = "f128")]
+scalb_impl!(f128, u128, 112, 16383);
+
+/// Useful constants for radians.
+pub trait Radians {
+    /// Returns radians corresponding to 90 degrees.
+    fn _90() -> Self;
+
+    /// Returns radians corresponding to 180 degrees.
+    fn _180() -> Self;
+
+    /// Returns radians corresponding to 360 degrees.
+    fn _360() -> Self;
+
+    /// Convert a value to radians from degrees.
+    /// Equivalent to ```value * (π / 180)```.
+    fn deg_to_rad(self) -> Self;
+
+    /// Convert a value to degrees from radians.
+    /// Equivalent to ```value * (180 / π)```.
+    fn rad_to_deg(self) -> Self;
+}
+
+/// Converts degrees to radians in `const` contexts, like `Radians::deg_to_rad`.
+#[inline(always)]
+pub const fn deg_to_rad_f32(x: f32) -> f32 {
+    x * (::core::f32::consts::PI / 180.0_f32)
+}
+
+/// Converts radians to degrees in `const` contexts, like `Radians::rad_to_deg`.
+#[inline(always)]
+pub const fn rad_to_deg_f32(x: f32) -> f32 {
+    x * (180.0_f32 / ::core::f32::consts::PI)
+}
+
+/// Converts degrees to radians in `const` contexts, like `Radians::deg_to_rad`.
+#[inline(always)]
+pub const fn deg_to_rad_f64(x: f64) -> f64 {
+    x * (::core::f64::consts::PI / 180.0_f64)
+}
+
+/// Converts radians to degrees in `const` contexts, like `Radians::rad_to_deg`.
+#[inline(always)]
+pub const fn rad_to_deg_f64(x: f64) -> f64 {
+    x * (180.0_f64 / ::core::f64::consts::PI)
+}
+
+impl Radians for f32 {
+    #[inline(always)]
+    fn _90() -> f32 {
+        ::core::f32::consts::FRAC_PI_2
+    }
+
+    #[inline(always)]
+    fn _180() -> f32 {
+        ::core::f32::consts::PI
+    }
+
+    #[inline(always)]
+    fn _360() -> f32 {
+        <Self as Radians>::_180() * 2.0
+    }
+
+    #[inline(always)]
+    fn deg_to_rad(self) -> Self {
+        deg_to_rad_f32(self)
+    }
+
+    #[inline(always)]
+    fn rad_to_deg(self) -> Self {
+        rad_to_deg_f32(self)
+    }
+}
+
+impl Radians for f64 {
+    #[inline(always)]
+    fn _90() -> f64 {
+        ::core::f64::consts::FRAC_PI_2
+    }
+
+    #[inline(always)]
+    fn _180() -> f64 {
+        ::core::f64::consts::PI
+    }
+
+    #[inline(always)]
+    fn _360() -> f64 {
+        <Self as Radians>::_180() * 2.0
+    }
+
+    #[inline(always)]
+    fn deg_to_rad(self) -> Self {
+        deg_to_rad_f64(self)
+    }
+
+    #[inline(always)]
+    fn rad_to_deg(self) -> Self {
+        rad_to_deg_f64(self)
+    }
+}
+
+/// Radians constants as associated constants, for use in `const` contexts.
+pub trait ConstRadians: Radians {
+    /// Radians corresponding to 90 degrees.
+    const QUARTER_TURN: Self;
+
+    /// Radians corresponding to 180 degrees.
+    const HALF_TURN: Self;
+
+    /// Radians corresponding to 360 degrees.
+    const FULL_TURN: Self;
+}
+
+impl ConstRadians for f32 {
+    const QUARTER_TURN: f32 = ::core::f32::consts::FRAC_PI_2;
+    const HALF_TURN: f32 = ::core::f32::consts::PI;
+    const FULL_TURN: f32 = ::core::f32::consts::TAU;
+}
+
+impl ConstRadians for f64 {
+    const QUARTER_TURN: f64 = ::core::f64::consts::FRAC_PI_2;
+    const HALF_TURN: f64 = ::core::f64::consts::PI;
+    const FULL_TURN: f64 = ::core::f64::consts::TAU;
+}
+
+/// Angle wrapping and interpolation, in radians.
+pub trait Angle: Sized {
+    /// Wraps the angle into the range `[-π, π)`.
+    fn wrap_to_pi(self) -> Self;
+
+    /// Wraps the angle into the range `[0, 2π)`.
+    fn wrap_to_tau(self) -> Self;
+
+    /// Returns the signed shortest rotation from self to other, in the range `[-π, π)`.
+    fn angle_delta(self, other: Self) -> Self;
+
+    /// Interpolates from self to other along the shortest path.
+    ///
+    /// The result is not wrapped, so it changes continuously with `t`.
+    fn angle_lerp(self, other: Self, t: Self) -> Self;
+}
+
+impl<T: Float> Angle for T {
+    #[inline(always)]
+    fn wrap_to_pi(self) -> T {
+        let r = self % T::_360();
+        if r >= T::_180() {
+            r - T::_360()
+        } else if r < -T::_180() {
+            r + T::_360()
+        } else {
+            r
+        }
+    }
+
+    #[inline(always)]
+    fn wrap_to_tau(self) -> T {
+        let mut r = self % T::_360();
+        if r < T::zero() { r += T::_360(); }
+        // Adding to a tiny negative remainder can round up to 2π.
+        if r >= T::_360() { T::zero() } else { r }
+    }
+
+    #[inline(always)]
+    fn angle_delta(self, other: T) -> T {
+        (other - self).wrap_to_pi()
+    }
+
+    #[inline(always)]
+    fn angle_lerp(self, other: T, t: T) -> T {
+        self + self.angle_delta(other) * t
+    }
+}
+
+/// Useful mathematical constants and limits.
+pub trait Consts {
+    /// Returns Archimedes' constant (π).
+    fn pi() -> Self;
+    /// Returns the full circle constant (τ = 2π).
+    fn tau() -> Self;
+    /// Returns Euler's number (e).
+    fn e() -> Self;
+    /// Returns the difference between 1 and the next larger representable number.
+    fn epsilon() -> Self;
+    /// Returns the smallest finite value.
+    fn min_value() -> Self;
+    /// Returns the largest finite value.
+    fn max_value() -> Self;
+    /// Returns the smallest positive normal value.
+    fn min_positive() -> Self;
+    /// Returns positive infinity.
+    fn infinity() -> Self;
+    /// Returns negative infinity.
+    fn neg_infinity() -> Self;
+    /// Returns Not a Number (NaN).
+    fn nan() -> Self;
+}
+
+impl Consts for f32 {
+    #[inline(always)]
+    fn pi() -> f32 { ::core::f32::consts::PI }
+
+    #[inline(always)]
+    fn tau() -> f32 { ::core::f32::consts::TAU }
+
+    #[inline(always)]
+    fn e() -> f32 { ::core::f32::consts::E }
+
+    #[inline(always)]
+    fn epsilon() -> f32 { f32::EPSILON }
+
+    #[inline(always)]
+    fn min_value() -> f32 { f32::MIN }
+
+    #[inline(always)]
+    fn max_value() -> f32 { f32::MAX }
+
+    #[inline(always)]
+    fn min_positive() -> f32 { f32::MIN_POSITIVE }
+
+    #[inline(always)]
+    fn infinity() -> f32 { f32::INFINITY }
+
+    #[inline(always)]
+    fn neg_infinity() -> f32 { f32::NEG_INFINITY }
+
+    #[inline(always)]
+    fn nan() -> f32 { f32::NAN }
+}
+
+impl Consts for f64 {
+    #[inline(always)]
+    fn pi() -> f64 { ::core::f64::consts::PI }
+
+    #[inline(always)]
+    fn tau() -> f64 { ::core::f64::consts::TAU }
+
+    #[inline(always)]
+    fn e() -> f64 { ::core::f64::consts::E }
+
+    #[inline(always)]
+    fn epsilon() -> f64 { f64::EPSILON }
+
+    #[inline(always)]
+    fn min_value() -> f64 { f64::MIN }
+
+    #[inline(always)]
+    fn max_value() -> f64 { f64::MAX }
+
+    #[inline(always)]
+    fn min_positive() -> f64 { f64::MIN_POSITIVE }
+
+    #[inline(always)]
+    fn infinity() -> f64 { f64::INFINITY }
+
+    #[inline(always)]
+    fn neg_infinity() -> f64 { f64::NEG_INFINITY }
+
+    #[inline(always)]
+    fn nan() -> f64 { f64::NAN }
+}
+
+/// Number 1.
+pub trait One {
+    /// Returns 1.
+    fn one() -> Self;
+}
+
+/// Number 0.
+pub trait Zero {
+    /// Returns 0.
+    fn zero() -> Self;
+}
+
+impl One for f32 {
+    #[inline(always)]
+    fn one() -> f32 { 1.0 }
+}
+
+impl One for f64 {
+    #[inline(always)]
+    fn one() -> f64 { 1.0 }
+}
+
+impl Zero for f32 {
+    #[inline(always)]
+    fn zero() -> f32 { 0.0 }
+}
+
+impl Zero for f64 {
+    #[inline(always)]
+    fn zero() -> f64 { 0.0 }
+}
+
+/// Number 1 as an associated constant, for use in `const` contexts.
+pub trait ConstOne: One {
+    /// 1.
+    const ONE: Self;
+}
+
+/// Number 0 as an associated constant, for use in `const` contexts.
+pub trait ConstZero: Zero {
+    /// 0.
+    const ZERO: Self;
+}
+
+impl ConstOne for f32 {
+    const ONE: f32 = 1.0;
+}
+
+impl ConstOne for f64 {
+    const ONE: f64 = 1.0;
+}
+
+impl ConstZero for f32 {
+    const ZERO: f32 = 0.0;
+}
+
+impl ConstZero for f64 {
+    const ZERO: f64 = 0.0;
+}
+
+/// Square root.
+pub trait Sqrt {
+    /// Returns square root.
+    fn sqrt(self) -> Self;
+}
+
+impl Sqrt for f32 {
+    #[inline(always)]
+    fn sqrt(self) -> f32 { portable::sqrtf(self) }
+}
+
+impl Sqrt for f64 {
+    #[inline(always)]
+    fn sqrt(self) -> f64 { portable::sqrt(self) }
+}
+
+/// Reciprocal.
+pub trait Recip {
+    /// Returns `1 / self`.
+    fn recip(self) -> Self;
+
+    /// Returns `1 / sqrt(self)`.
+    #[inline(always)]
+    fn rsqrt(self) -> Self where Self: Sqrt + Sized {
+        self.recip().sqrt()
+    }
+}
+
+impl Recip for f32 {
+    #[inline(always)]
+    fn recip(self) -> f32 { 1.0 / self }
+
+    #[inline(always)]
+    fn rsqrt(self) -> f32 { 1.0 / portable::sqrtf(self) }
+}
+
+impl Recip for f64 {
+    #[inline(always)]
+    fn recip(self) -> f64 { 1.0 / self }
+
+    #[inline(always)]
+    fn rsqrt(self) -> f64 { 1.0 / portable::sqrt(self) }
+}
+
+/// Cube root.
+pub trait Cbrt {
+    /// Returns cube root.
+    fn cbrt(self) -> Self;
+}
+
+impl Cbrt for f32 {
+    #[inline(always)]
+    fn cbrt(self) -> f32 { math::cbrtf(self) }
+}
+
+impl Cbrt for f64 {
+    #[inline(always)]
+    fn cbrt(self) -> f64 { math::cbrt(self) }
+}
+
+/// Basic trigonometry functions
+pub trait Trig {
+    /// Returns sine of self.
+    fn sin(self) -> Self;
+    /// Returns cosine of self.
+    fn cos(self) -> Self;
+    /// Returns tangent of self.
+    fn tan(self) -> Self;
+    /// Returns inverse sine of self.
+    fn asin(self) -> Self;
+    /// Returns inverse cosine of self.
+    fn acos(self) -> Self;
+    /// Returns inverse tangent of self.
+    fn atan(self) -> Self;
+    /// Returns the four quadrant arctangent of self (y) and other (x).
+    fn atan2(self, other: Self) -> Self;
+    /// Returns hyperbolic sine of self.
+    fn sinh(self) -> Self;
+    /// Returns hyperbolic cosine of self.
+    fn cosh(self) -> Self;
+    /// Returns hyperbolic tangent of self.
+    fn tanh(self) -> Self;
+    /// Returns inverse hyperbolic sine of self.
+    fn asinh(self) -> Self;
+    /// Returns inverse hyperbolic cosine of self.
+    fn acosh(self) -> Self;
+    /// Returns inverse hyperbolic tangent of self.
+    fn atanh(self) -> Self;
+    /// Returns sine and cosine of self.
+    ///
+    /// Computing both at once shares the argument reduction,
+    /// which is faster than calling `sin` and `cos` separately.
+    #[inline(always)]
+    fn sin_cos(self) -> (Self, Self) where Self: Copy + Sized {
+        (self.sin(), self.cos())
+    }
+    /// Returns the unnormalized sinc function, `sin(self) / self`, which is 1 at zero.
+    ///
+    /// Uses a Taylor polynomial near zero, where the quotient loses precision.
+    #[inline(always)]
+    fn sinc(self) -> Self where Self: Float {
+        let x2 = self * self;
+        if self.abs() < Self::epsilon().sqrt().sqrt() {
+            Self::one() - x2 / Self::from_f64(6.0) * (Self::one() - x2 / Self::from_f64(20.0))
+        } else {
+            self.sin() / self
+        }
+    }
+    /// Returns `cos(self) - 1`, without the cancellation for small angles.
+    #[inline(always)]
+    fn cos_m1(self) -> Self where Self: Float {
+        let h = (self / Self::from_f64(2.0)).sin();
+        -Self::from_f64(2.0) * h * h
+    }
+    /// Returns sine of self in degrees.
+    #[inline(always)]
+    fn sin_deg(self) -> Self where Self: Radians + Sized {
+        self.deg_to_rad().sin()
+    }
+    /// Returns cosine of self in degrees.
+    #[inline(always)]
+    fn cos_deg(self) -> Self where Self: Radians + Sized {
+        self.deg_to_rad().cos()
+    }
+    /// Returns tangent of self in degrees.
+    #[inline(always)]
+    fn tan_deg(self) -> Self where Self: Radians + Sized {
+        self.deg_to_rad().tan()
+    }
+    /// Returns the four quadrant arctangent of self (y) and other (x) in degrees.
+    #[inline(always)]
+    fn atan2_deg(self, other: Self) -> Self where Self: Radians + Sized {
+        self.atan2(other).rad_to_deg()
+    }
+}
+
+impl Trig for f32 {
+    #[inline(always)]
+    fn sin(self) -> f32 { portable::sinf(self) }
+
+    #[inline(always)]
+    fn cos(self) -> f32 { portable::cosf(self) }
+
+    #[inline(always)]
+    fn tan(self) -> f32 { portable::tanf(self) }
+
+    #[inline(always)]
+    fn asin(self) -> f32 { portable::asinf(self) }
+
+    #[inline(always)]
+    fn acos(self) -> f32 { portable::acosf(self) }
+
+    #[inline(always)]
+    fn atan(self) -> f32 { portable::atanf(self) }
+
+    #[inline(always)]
+    fn atan2(self, other: f32) -> f32 { portable::atan2f(self, other) }
+
+    #[inline(always)]
+    fn sinh(self) -> f32 { portable::sinhf(self) }
+
+    #[inline(always)]
+    fn cosh(self) -> f32 { portable::coshf(self) }
+
+    #[inline(always)]
+    fn tanh(self) -> f32 { portable::tanhf(self) }
+
+    #[inline(always)]
+    fn asinh(self) -> f32 { portable::asinhf(self) }
+
+    #[inline(always)]
+    fn acosh(self) -> f32 { portable::acoshf(self) }
+
+    #[inline(always)]
+    fn atanh(self) -> f32 { portable::atanhf(self) }
+
+    #[inline(always)]
+    fn sin_cos(self) -> (f32, f32) { portable::sincosf(self) }
+}
+
+impl Trig for f64 {
+    #[inline(always)]
+    fn sin(self) -> f64 { portable::sin(self) }
+
+    #[inline(always)]
+    fn cos(self) -> f64 { portable::cos(self) }
+
+    #[inline(always)]
+    fn tan(self) -> f64 { portable::tan(self) }
+
+    #[inline(always)]
+    fn asin(self) -> f64 { portable::asin(self) }
+
+    #[inline(always)]
+    fn acos(self) -> f64 { portable::acos(self) }
+
+    #[inline(always)]
+    fn atan(self) -> f64 { portable::atan(self) }
+
+    #[inline(always)]
+    fn atan2(self, other: f64) -> f64 { portable::atan2(self, other) }
+
+    #[inline(always)]
+    fn sinh(self) -> f64 { portable::sinh(self) }
+
+    #[inline(always)]
+    fn cosh(self) -> f64 { portable::cosh(self) }
+
+    #[inline(always)]
+    fn tanh(self) -> f64 { portable::tanh(self) }
+
+    #[inline(always)]
+    fn asinh(self) -> f64 { portable::asinh(self) }
+
+    #[inline(always)]
+    fn acosh(self) -> f64 { portable::acosh(self) }
+
+    #[inline(always)]
+    fn atanh(self) -> f64 { portable::atanh(self) }
+
+    #[inline(always)]
+    fn sin_cos(self) -> (f64, f64) { portable::sincos(self) }
+}
+
+/// Casts into another type.
+pub trait Cast<T> {
+    /// Casts into other type.
+    fn cast(self) -> T;
+}
+
+impl Cast<f32> for f64 {
+    #[inline(always)]
+    fn cast(self) -> f32 { self as f32 }
+}
+
+impl Cast<f64> for f32 {
+    #[inline(always)]
+    fn cast(self) -> f64 { self as f64 }
+}
+
+impl Cast<f32> for f32 {
+    #[inline(always)]
+    fn cast(self) -> f32 { self }
+}
+
+impl Cast<f64> for f64 {
+    #[inline(always)]
+    fn cast(self) -> f64 { self }
+}
+
+macro_rules! cast_int_impls {
+    ($($t:ident => $($i:ident),*;)*) => {
+        $($(
+            impl Cast<$i> for $t {
+                #[inline(always)]
+                fn cast(self) -> $i { self as $i }
+            }
+
+            impl CastRounded<$i> for $t {
+                #[inline(always)]
+                fn cast_rounded(self, mode: RoundingMode) -> $i {
+                    match mode {
+                        RoundingMode::Floor => Round::floor(self) as $i,
+                        RoundingMode::Ceil => Round::ceil(self) as $i,
+                        RoundingMode::Round => Round::round(self) as $i,
+                        RoundingMode::Trunc => self as $i,
+                    }
+                }
+            }
+        )*)*
+    };
+}
+
+/// Rounding mode for casting into integers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round toward negative infinity.
+    Floor,
+    /// Round toward positive infinity.
+    Ceil,
+    /// Round to nearest, half-way cases away from zero.
+    Round,
+    /// Round toward zero.
+    Trunc,
+}
+
+/// Casts into another type with an explicit rounding mode.
+///
+/// Values out of range saturate at the bounds of the target type, and NaN becomes 0.
+pub trait CastRounded<T> {
+    /// Casts into other type, rounding with the given mode.
+    fn cast_rounded(self, mode: RoundingMode) -> T;
+}
+
+cast_int_impls! {
+    f32 => i32, u32, i64, u64, usize;
+    f64 => i32, u32, i64, u64, usize;
+}
+
+#[cfg(feature = "f128")]
+cast_int_impls! {
+    f128 => i32, u32, i64, u64, usize;
+}
+
+/// Trait for converting from different numeric types
+pub trait FromPrimitive {
+    /// from a f64
+    fn from_f64(t: f64) -> Self;
+    /// from a f32
+    fn from_f32(t: f32) -> Self;
+    /// from a isze
+    fn from_isize(t: isize) -> Self;
+    /// from a u32
+    fn from_u32(t: u32) -> Self;
+    /// from a i32
+    fn from_i32(t: i32) -> Self;
+    /// from a u64
+    fn from_u64(t: u64) -> Self;
+    /// from a i64
+    fn from_i64(t: i64) -> Self;
+    /// from a usize
+    fn from_usize(t: usize) -> Self;
+    /// from a u8
+    fn from_u8(t: u8) -> Self;
+    /// from a i8
+    fn from_i8(t: i8) -> Self;
+    /// from a u16
+    fn from_u16(t: u16) -> Self;
+    /// from a i16
+    fn from_i16(t: i16) -> Self;
+    // Add more as needed..
+}
+
+impl FromPrimitive for f64 {
+    #[inline(always)]
+    fn from_f64(t: f64) -> Self { t }
+    #[inline(always)]
+    fn from_f32(t: f32) -> Self { t as f64 }
+    #[inline(always)]
+    fn from_isize(t: isize) -> Self { t as f64 }
+    #[inline(always)]
+    fn from_u32(t: u32) -> Self { t as f64 }
+    #[inline(always)]
+    fn from_i32(t: i32) -> Self { t as f64 }
+    #[inline(always)]
+    fn from_u64(t: u64) -> Self { t as f64 }
+    #[inline(always)]
+    fn from_i64(t: i64) -> Self { t as f64 }
+    #[inline(always)]
+    fn from_usize(t: usize) -> Self { t as f64 }
+    #[inline(always)]
+    fn from_u8(t: u8) -> Self { t as f64 }
+    #[inline(always)]
+    fn from_i8(t: i8) -> Self { t as f64 }
+    #[inline(always)]
+    fn from_u16(t: u16) -> Self { t as f64 }
+    #[inline(always)]
+    fn from_i16(t: i16) -> Self { t as f64 }
+}
+
+impl FromPrimitive for f32 {
+    #[inline(always)]
+    fn from_f64(t: f64) -> Self { t as f32 }
+    #[inline(always)]
+    fn from_f32(t: f32) -> Self { t }
+    #[inline(always)]
+    fn from_isize(t: isize) -> Self { t as f32 }
+    #[inline(always)]
+    fn from_u32(t: u32) -> Self { t as f32 }
+    #[inline(always)]
+    fn from_i32(t: i32) -> Self { t as f32 }
+    #[inline(always)]
+    fn from_u64(t: u64) -> Self { t as f32 }
+    #[inline(always)]
+    fn from_i64(t: i64) -> Self { t as f32 }
+    #[inline(always)]
+    fn from_usize(t: usize) -> Self { t as f32 }
+    #[inline(always)]
+    fn from_u8(t: u8) -> Self { t as f32 }
+    #[inline(always)]
+    fn from_i8(t: i8) -> Self { t as f32 }
+    #[inline(always)]
+    fn from_u16(t: u16) -> Self { t as f32 }
+    #[inline(always)]
+    fn from_i16(t: i16) -> Self { t as f32 }
+}
+
+/// Trait for converting into different numeric types.
+///
+/// Conversions into integers truncate toward zero.
+/// The plain versions saturate at the bounds of the integer type and map NaN to 0,
+/// like `as` casts, while the `checked_` versions return `None` instead.
+pub trait ToPrimitive {
+    /// to a f64
+    fn to_f64(self) -> f64;
+    /// to a f32
+    fn to_f32(self) -> f32;
+    /// to a i64
+    fn to_i64(self) -> i64;
+    /// to a u64
+    fn to_u64(self) -> u64;
+    /// to a i32
+    fn to_i32(self) -> i32;
+    /// to a u32
+    fn to_u32(self) -> u32;
+    /// to a isize
+    fn to_isize(self) -> isize;
+    /// to a usize
+    fn to_usize(self) -> usize;
+    /// to a i64, or `None` if NaN or out of range
+    fn checked_to_i64(self) -> Option<i64>;
+    /// to a u64, or `None` if NaN or out of range
+    fn checked_to_u64(self) -> Option<u64>;
+    /// to a i32, or `None` if NaN or out of range
+    fn checked_to_i32(self) -> Option<i32>;
+    /// to a u32, or `None` if NaN or out of range
+    fn checked_to_u32(self) -> Option<u32>;
+    /// to a isize, or `None` if NaN or out of range
+    fn checked_to_isize(self) -> Option<isize>;
+    /// to a usize, or `None` if NaN or out of range
+    fn checked_to_usize(self) -> Option<usize>;
+}
+
+macro_rules! checked_to_int {
+    ($x:expr, $t:ident, $i:ident, signed) => {{
+        let x = $x;
+        if x >= $i::MIN as $t && x < -($i::MIN as $t) { Some(x as $i) } else { None }
+    }};
+    ($x:expr, $t:ident, $i:ident, unsigned) => {{
+        let x = $x;
+        if x >= 0.0 && x < $i::MAX as $t + 1.0 { Some(x as $i) } else { None }
+    }};
+}
+
+macro_rules! to_primitive_impl {
+    ($t:ident, $trunc:path) => {
+        impl ToPrimitive for $t {
+            #[inline(always)]
+            fn to_f64(self) -> f64 { self as f64 }
+            #[inline(always)]
+            fn to_f32(self) -> f32 { self as f32 }
+            #[inline(always)]
+            fn to_i64(self) -> i64 { self as i64 }
+            #[inline(always)]
+            fn to_u64(self) -> u64 { self as u64 }
+            #[inline(always)]
+            fn to_i32(self) -> i32 { self as i32 }
+            #[inline(always)]
+            fn to_u32(self) -> u32 { self as u32 }
+            #[inline(always)]
+            fn to_isize(self) -> isize { self as isize }
+            #[inline(always)]
+            fn to_usize(self) -> usize { self as usize }
+            #[inline(always)]
+            fn checked_to_i64(self) -> Option<i64> { checked_to_int!($trunc(self), $t, i64, signed) }
+            #[inline(always)]
+            fn checked_to_u64(self) -> Option<u64> { checked_to_int!($trunc(self), $t, u64, unsigned) }
+            #[inline(always)]
+            fn checked_to_i32(self) -> Option<i32> { checked_to_int!($trunc(self), $t, i32, signed) }
+            #[inline(always)]
+            fn checked_to_u32(self) -> Option<u32> { checked_to_int!($trunc(self), $t, u32, unsigned) }
+            #[inline(always)]
+            fn checked_to_isize(self) -> Option<isize> { checked_to_int!($trunc(self), $t, isize, signed) }
+            #[inline(always)]
+            fn checked_to_usize(self) -> Option<usize> { checked_to_int!($trunc(self), $t, usize, unsigned) }
+        }
+    };
+}
+
+to_primitive_impl!(f32, math::truncf);
+to_primitive_impl!(f64, math::trunc);
+#[cfg(feature = "f128")]
+to_primitive_impl!(f128, f128::trunc);
+
+/// Interpolation between two values.
+pub trait Lerp: Sized {
+    /// Linearly interpolates from self to other.
+    ///
+    /// Returns self when `t = 0` and other when `t = 1`,
+    /// and extrapolates for `t` outside `[0, 1]`.
+    fn lerp(self, other: Self, t: Self) -> Self;
+
+    /// Returns the parameter `t` such that `self.lerp(other, t) == value`.
+    ///
+    /// The division is not guarded, so self equal to other returns NaN or infinity.
+    fn inverse_lerp(self, other: Self, value: Self) -> Self;
+
+    /// Maps self from `in_range` to `out_range` linearly, without clamping.
+    fn remap(self, in_range: (Self, Self), out_range: (Self, Self)) -> Self;
+
+    /// Returns a smooth Hermite interpolation between 0 and 1,
+    /// clamped to 0 below `edge0` and to 1 above `edge1`.
+    fn smoothstep(self, edge0: Self, edge1: Self) -> Self;
+
+    /// Like `smoothstep`, but with zero first and second derivatives at the edges.
+    fn smootherstep(self, edge0: Self, edge1: Self) -> Self;
+}
+
+impl<T: Float> Lerp for T {
+    #[inline(always)]
+    fn lerp(self, other: T, t: T) -> T {
+        self * (T::one() - t) + other * t
+    }
+
+    #[inline(always)]
+    fn inverse_lerp(self, other: T, value: T) -> T {
+        (value - self) / (other - self)
+    }
+
+    #[inline(always)]
+    fn remap(self, in_range: (T, T), out_range: (T, T)) -> T {
+        out_range.0.lerp(out_range.1, in_range.0.inverse_lerp(in_range.1, self))
+    }
+
+    #[inline(always)]
+    fn smoothstep(self, edge0: T, edge1: T) -> T {
+        let t = edge0.inverse_lerp(edge1, self).clamp01();
+        t * t * (T::from_f64(3.0) - T::from_f64(2.0) * t)
+    }
+
+    #[inline(always)]
+    fn smootherstep(self, edge0: T, edge1: T) -> T {
+        let t = edge0.inverse_lerp(edge1, self).clamp01();
+        t * t * t * (t * (t * T::from_f64(6.0) - T::from_f64(15.0)) + T::from_f64(10.0))
+    }
+}
+
+/// Arithmetic that returns `None` instead of a NaN or infinite result,
+/// to catch the operation where a computation goes wrong.
+pub trait CheckedOps: Sized {
+    /// Returns `self + other`, or `None` if the result is not finite.
+    fn checked_add(self, other: Self) -> Option<Self>;
+    /// Returns `self - other`, or `None` if the result is not finite.
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    /// Returns `self * other`, or `None` if the result is not finite.
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    /// Returns `self / other`, or `None` if the result is not finite.
+    fn checked_div(self, other: Self) -> Option<Self>;
+    /// Returns `self % other`, or `None` if the result is not finite.
+    fn checked_rem(self, other: Self) -> Option<Self>;
+    /// Returns the square root, or `None` if the result is not finite.
+    fn checked_sqrt(self) -> Option<Self>;
+    /// Returns `self^other`, or `None` if the result is not finite.
+    fn checked_powf(self, other: Self) -> Option<Self>;
+    /// Returns the natural logarithm, or `None` if the result is not finite.
+    fn checked_ln(self) -> Option<Self>;
+    /// Returns self if finite, otherwise `fallback`.
+    fn finite_or(self, fallback: Self) -> Self;
+}
+
+impl<T: Float> CheckedOps for T {
+    #[inline(always)]
+    fn checked_add(self, other: T) -> Option<T> { finite(self + other) }
+
+    #[inline(always)]
+    fn checked_sub(self, other: T) -> Option<T> { finite(self - other) }
+
+    #[inline(always)]
+    fn checked_mul(self, other: T) -> Option<T> { finite(self * other) }
+
+    #[inline(always)]
+    fn checked_div(self, other: T) -> Option<T> { finite(self / other) }
+
+    #[inline(always)]
+    fn checked_rem(self, other: T) -> Option<T> { finite(self % other) }
+
+    #[inline(always)]
+    fn checked_sqrt(self) -> Option<T> { finite(self.sqrt()) }
+
+    #[inline(always)]
+    fn checked_powf(self, other: T) -> Option<T> { finite(self.powf(other)) }
+
+    #[inline(always)]
+    fn checked_ln(self) -> Option<T> { finite(self.ln()) }
+
+    #[inline(always)]
+    fn finite_or(self, fallback: T) -> T { if self.is_finite() { self } else { fallback } }
+}
+
+#[inline(always)]
+fn finite<T: Float>(x: T) -> Option<T> { if x.is_finite() { Some(x) } else { None } }
+
+/// Conversion between floats and normalized integers, as used for packed vertex data and textures.
+///
+/// Follows the GPU conventions: unorm maps `[0, 1]` onto the full unsigned range,
+/// snorm maps `[-1, 1]` onto the signed range without its minimum,
+/// which decodes to `-1` as well. Encoding clamps to the range, rounds to nearest
+/// and maps NaN to 0.
+pub trait Quantize: Sized {
+    /// Encodes as an 8 bit unorm.
+    fn to_unorm8(self) -> u8;
+    /// Encodes as a 16 bit unorm.
+    fn to_unorm16(self) -> u16;
+    /// Decodes an 8 bit unorm.
+    fn from_unorm8(v: u8) -> Self;
+    /// Decodes a 16 bit unorm.
+    fn from_unorm16(v: u16) -> Self;
+    /// Encodes as an 8 bit snorm.
+    fn to_snorm8(self) -> i8;
+    /// Encodes as a 16 bit snorm.
+    fn to_snorm16(self) -> i16;
+    /// Decodes an 8 bit snorm.
+    fn from_snorm8(v: i8) -> Self;
+    /// Decodes a 16 bit snorm.
+    fn from_snorm16(v: i16) -> Self;
+}
+
+impl<T: Float> Quantize for T {
+    #[inline(always)]
+    fn to_unorm8(self) -> u8 { (self.clamp01() * T::from_u8(u8::MAX)).round().to_u32() as u8 }
+
+    #[inline(always)]
+    fn to_unorm16(self) -> u16 {
+        (self.clamp01() * T::from_u16(u16::MAX)).round().to_u32() as u16
+    }
+
+    #[inline(always)]
+    fn from_unorm8(v: u8) -> T { T::from_u8(v) / T::from_u8(u8::MAX) }
+
+    #[inline(always)]
+    fn from_unorm16(v: u16) -> T { T::from_u16(v) / T::from_u16(u16::MAX) }
+
+    #[inline(always)]
+    fn to_snorm8(self) -> i8 {
+        (self.clamp(-T::one(), T::one()) * T::from_i8(i8::MAX)).round().to_i32() as i8
+    }
+
+    #[inline(always)]
+    fn to_snorm16(self) -> i16 {
+        (self.clamp(-T::one(), T::one()) * T::from_i16(i16::MAX)).round().to_i32() as i16
+    }
+
+    #[inline(always)]
+    fn from_snorm8(v: i8) -> T { (T::from_i8(v) / T::from_i8(i8::MAX)).max(-T::one()) }
+
+    #[inline(always)]
+    fn from_snorm16(v: i16) -> T { (T::from_i16(v) / T::from_i16(i16::MAX)).max(-T::one()) }
+}
+
+/// Conversion between durations in seconds and integer tick counts, as used by fixed-timestep loops.
+pub trait TimeCast: Sized {
+    /// Returns the number of ticks in self seconds, rounded to nearest.
+    ///
+    /// Rounding rather than truncating keeps the count exact for multiples of the tick length
+    /// that carry rounding error. Negative durations and NaN give 0,
+    /// and durations beyond the range saturate at `u64::MAX`.
+    fn to_ticks(self, ticks_per_second: u64) -> u64;
+    /// Returns the duration of `ticks` in seconds.
+    ///
+    /// Divides the whole seconds out first, so large tick counts keep their fraction.
+    /// Panics if `ticks_per_second` is zero.
+    fn from_ticks(ticks: u64, ticks_per_second: u64) -> Self;
+}
+
+impl<T: Float> TimeCast for T {
+    #[inline(always)]
+    fn to_ticks(self, ticks_per_second: u64) -> u64 {
+        (self * T::from_u64(ticks_per_second)).round().to_u64()
+    }
+
+    #[inline(always)]
+    fn from_ticks(ticks: u64, ticks_per_second: u64) -> T {
+        let (seconds, rest) = (ticks / ticks_per_second, ticks % ticks_per_second);
+        T::from_u64(seconds) + T::from_u64(rest) / T::from_u64(ticks_per_second)
+    }
+}
+
+/// Fast approximations for hot loops where throughput matters more than accuracy.
+///
+/// The error bounds are measured against the correctly rounded result
+/// and hold for both `f32` and `f64`, since the approximation dominates the rounding error.
+pub trait FastApprox {
+    /// Approximates the sine, with an absolute error below `1.1e-3`.
+    ///
+    /// The angle is first wrapped into `[-π, π]`, which loses precision for large inputs.
+    fn fast_sin(self) -> Self;
+    /// Approximates the cosine, with an absolute error below `1.1e-3`.
+    ///
+    /// The angle is first wrapped into `[-π, π]`, which loses precision for large inputs.
+    fn fast_cos(self) -> Self;
+    /// Approximates `1 / sqrt(self)` with the bit trick and one Newton step,
+    /// with a relative error below `1.8e-3` for positive normal inputs.
+    fn fast_inv_sqrt(self) -> Self;
+    /// Approximates `e^self` with a relative error below `5e-6`.
+    fn fast_exp(self) -> Self;
+    /// Approximates the four quadrant arctangent of `self / other`,
+    /// with an absolute error below `1.2e-5`.
+    ///
+    /// Returns `0` when both arguments are zero.
+    fn fast_atan2(self, other: Self) -> Self;
+}
+
+macro_rules! fast_approx_impl {
+    ($t:ident, $bits:ident, $round:path,
+     $magic:expr, $mantissa:expr, $bias:expr, $max_exp:expr, $min_exp:expr) => {
+        impl FastApprox for $t {
+            #[inline(always)]
+            fn fast_sin(self) -> $t {
+                use core::$t::consts::{FRAC_1_PI, PI};
+
+                const B: $t = 4.0 * FRAC_1_PI;
+                const C: $t = -4.0 * FRAC_1_PI * FRAC_1_PI;
+                const P: $t = 0.225;
+
+                let x = self - 2.0 * PI * $round(self * 0.5 * FRAC_1_PI);
+                let y = B * x + C * x * x.abs();
+                P * (y * y.abs() - y) + y
+            }
+
+            #[inline(always)]
+            fn fast_cos(self) -> $t {
+                (self + core::$t::consts::FRAC_PI_2).fast_sin()
+            }
+
+            #[inline(always)]
+            fn fast_inv_sqrt(self) -> $t {
+                let y = $t::from_bits($magic - (self.to_bits() >> 1));
+                y * (1.5 - 0.5 * self * y * y)
+            }
+
+            #[inline(always)]
+            fn fast_exp(self) -> $t {
+                use core::$t::consts::{LN_2, LOG2_E};
+
+                let t = self * LOG2_E;
+                if t >= $max_exp { return $t::INFINITY; }
+                if t < $min_exp { return 0.0; }
+                // Split into `n + f` with `f` in `[-0.5, 0.5]`,
+                // so the series for `2^f` converges quickly.
+                let n = $round(t);
+                let f = (t - n) * LN_2;
+                let p = 1.0 + f * (1.0 + f * (1.0 / 2.0 + f * (1.0 / 6.0
+                    + f * (1.0 / 24.0 + f * (1.0 / 120.0 + f * (1.0 / 720.0))))));
+                // Scale in two steps so subnormal and near overflow results are exact.
+                let n = n as i32;
+                let (n1, n2) = (n >> 1, n - (n >> 1));
+                let s1 = $t::from_bits(((n1 + $bias) as $bits) << $mantissa);
+                let s2 = $t::from_bits(((n2 + $bias) as $bits) << $mantissa);
+                p * s1 * s2
+            }
+
+            #[inline(always)]
+            fn fast_atan2(self, other: $t) -> $t {
+                use core::$t::consts::{FRAC_PI_2, PI};
+
+                let (ay, ax) = (self.abs(), other.abs());
+                let (lo, hi) = if ay < ax { (ay, ax) } else { (ax, ay) };
+                let z = if hi == 0.0 { 0.0 } else { lo / hi };
+                let z2 = z * z;
+                let mut a = z * (0.999866 + z2 * (-0.3302995 + z2 * (0.180141
+                    + z2 * (-0.085133 + z2 * 0.0208351))));
+                if ay > ax { a = FRAC_PI_2 - a; }
+                if other < 0.0 { a = PI - a; }
+                if self < 0.0 { -a } else { a }
+            }
+        }
+    };
+}
+
+fast_approx_impl!(f32, u32, math::roundf, 0x5f37_59df, 23, 127, 128.0, -150.0);
+fast_approx_impl!(f64, u64, math::round, 0x5fe6_eb50_c7b5_37a9, 52, 1023, 1024.0, -1075.0);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_f32_sqrt() {
+        let a = 4.0_f32;
+        let b = <f32 as Sqrt>::sqrt(a);
+        assert!((b - 2.0_f32).abs() < f32::EPSILON)
+    }
+
+    #[test]
+    fn test_f64_sqrt() {
+        let a = 4.0_f64;
+        let b = <f64 as Sqrt>::sqrt(a);
+        assert!((b - 2.0_f64).abs() < f64::EPSILON)
+    }
+
+    #[test]
+    fn test_f32_deg_to_rad() {
+        let degrees = 23.0_f32;
+        let radians = degrees.deg_to_rad();
+        assert!((radians - 0.401_425).abs() > f32::EPSILON);
+    }
+
+    #[test]
+    fn test_f64_deg_to_rad() {
+        let degrees = 60.0_f64;
+        let radians = degrees.deg_to_rad();
+        assert!((radians - core::f64::consts::FRAC_PI_3).abs()  == f64::EPSILON);
+    }
+
+    #[test]
+    fn test_abs() {
+        assert_eq!(<f32 as Abs>::abs(-2.0), 2.0);
+        assert_eq!(<f64 as Abs>::copysign(2.0, -0.0), -2.0);
+        assert_eq!(<f64 as Abs>::copysign(-2.0, 1.0), 2.0);
+        assert_eq!(<f32 as Abs>::hypot(3.0, 4.0), 5.0);
+        assert_eq!(<f64 as Abs>::hypot(3e300, -4e300), 5e300);
+    }
+
+    #[test]
+    fn test_powi() {
+        assert_eq!(<f32 as Powi>::powi(2.0, 10), 1024.0);
+        assert_eq!(<f64 as Powi>::powi(2.0, -2), 0.25);
+        assert_eq!(<f64 as Powi>::powi(5.0, 0), 1.0);
+        assert_eq!(<f32 as Powi>::sqr(-3.0), 9.0);
+        assert_eq!(<f64 as Powi>::cube(-3.0), -27.0);
+    }
+
+    #[test]
+    fn test_mul_add() {
+        assert_eq!(<f32 as MulAdd>::mul_add(2.0, 3.0, 4.0), 10.0);
+        let a = 0.1_f64;
+        // The rounding error of `a * a` is only visible with a fused operation.
+        assert!(<f64 as MulAdd>::mul_add(a, a, -(a * a)) != 0.0);
+    }
+
+    #[test]
+    fn test_f32_exp_ln() {
+        let a = 2.0_f32;
+        let b = <f32 as Log>::ln(<f32 as Exp>::exp(a));
+        assert!((b - a).abs() < 4.0 * f32::EPSILON);
+    }
+
+    #[test]
+    fn test_f64_log() {
+        assert_eq!(<f64 as Log>::log2(8.0), 3.0);
+        assert_eq!(<f64 as Log>::log10(1000.0), 3.0);
+        assert_eq!(<f64 as Exp>::exp2(3.0), 8.0);
+    }
+
+    #[test]
+    fn test_round() {
+        assert_eq!(<f32 as Round>::floor(-1.5), -2.0);
+        assert_eq!(<f32 as Round>::ceil(-1.5), -1.0);
+        assert_eq!(<f64 as Round>::round(2.5), 3.0);
+        assert_eq!(<f64 as Round>::trunc(-2.7), -2.0);
+        assert_eq!(<f64 as Round>::fract(2.25), 0.25);
+    }
+
+    #[test]
+    fn test_classify() {
+        assert!(<f32 as Classify>::is_nan(f32::NAN));
+        assert!(<f64 as Classify>::is_infinite(f64::INFINITY));
+        assert!(!<f64 as Classify>::is_finite(f64::NEG_INFINITY));
+        assert!(!<f64 as Classify>::is_normal(0.0));
+        assert_eq!(<f32 as Classify>::classify(1e-40), FpCategory::Subnormal);
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        assert!((0.1_f64 + 0.2).approx_eq(0.3, f64::EPSILON));
+        assert!((0.1_f64 + 0.2).ulps_eq(0.3, 1));
+        assert!(!1.0_f32.ulps_eq(1.0 + 2.0 * f32::EPSILON, 1));
+        assert!(0.0_f32.ulps_eq(-0.0, 0));
+        assert!(!f64::NAN.ulps_eq(f64::NAN, u32::MAX));
+        assert_approx_eq!(1.0_f32, 1.05, 0.1);
+        assert_approx_eq!(0.1_f32 + 0.2, 0.3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_assert_approx_eq_fails() {
+        assert_approx_eq!(1.0_f64, 1.1);
+    }
+
+    #[test]
+    fn test_cast_int() {
+        let x: i32 = (-2.7_f32).cast();
+        assert_eq!(x, -2);
+        let x: u32 = (-2.7_f64).cast();
+        assert_eq!(x, 0);
+        let x: u64 = 1e30_f64.cast();
+        assert_eq!(x, u64::MAX);
+        let x: usize = 3.9_f32.cast();
+        assert_eq!(x, 3);
+        let x: i64 = f64::NAN.cast();
+        assert_eq!(x, 0);
+    }
+
+    #[test]
+    fn test_cast_rounded() {
+        let x: i32 = (-2.5_f64).cast_rounded(RoundingMode::Floor);
+        assert_eq!(x, -3);
+        let x: i32 = (-2.5_f64).cast_rounded(RoundingMode::Ceil);
+        assert_eq!(x, -2);
+        let x: i32 = (-2.5_f64).cast_rounded(RoundingMode::Round);
+        assert_eq!(x, -3);
+        let x: i32 = (-2.5_f64).cast_rounded(RoundingMode::Trunc);
+        assert_eq!(x, -2);
+        let x: u32 = 0.2_f32.cast_rounded(RoundingMode::Ceil);
+        assert_eq!(x, 1);
+        let x: u32 = (-0.2_f32).cast_rounded(RoundingMode::Floor);
+        assert_eq!(x, 0);
+        let x: i64 = f64::INFINITY.cast_rounded(RoundingMode::Round);
+        assert_eq!(x, i64::MAX);
+    }
+
+    #[test]
+    fn test_from_primitive() {
+        assert_eq!(f64::from_u64(1 << 40), 1099511627776.0);
+        assert_eq!(f32::from_i64(-3), -3.0);
+        assert_eq!(f64::from_usize(7), 7.0);
+        assert_eq!(f32::from_u8(255), 255.0);
+        assert_eq!(f64::from_i8(-128), -128.0);
+        assert_eq!(f32::from_u16(65535), 65535.0);
+        assert_eq!(f64::from_i16(-2), -2.0);
+    }
+
+    #[test]
+    fn test_to_primitive() {
+        assert_eq!(2.5_f32.to_f64(), 2.5);
+        assert_eq!(2.5_f64.to_f32(), 2.5);
+        assert_eq!((-2.7_f64).to_i64(), -2);
+        assert_eq!((-2.7_f64).to_u32(), 0);
+        assert_eq!(1e20_f32.to_i32(), i32::MAX);
+        assert_eq!(f64::NAN.to_usize(), 0);
+        assert_eq!((-2.7_f64).checked_to_i64(), Some(-2));
+        assert_eq!((-0.5_f32).checked_to_u64(), Some(0));
+        assert_eq!((-1.0_f32).checked_to_u64(), None);
+        assert_eq!(2147483647.5_f64.checked_to_i32(), Some(i32::MAX));
+        assert_eq!(2147483648.0_f64.checked_to_i32(), None);
+        assert_eq!((-2147483648.9_f64).checked_to_i32(), Some(i32::MIN));
+        assert_eq!(4294967296.0_f64.checked_to_u32(), None);
+        assert_eq!(9.3e18_f64.checked_to_i64(), None);
+        assert_eq!(1.8e19_f64.checked_to_u64(), Some(18000000000000000000));
+        assert_eq!(f32::NAN.checked_to_isize(), None);
+        assert_eq!(f64::INFINITY.checked_to_usize(), None);
+    }
+
+    #[test]
+    fn test_clamp() {
+        assert_eq!(<f32 as Clamp>::clamp(5.0, 0.0, 1.0), 1.0);
+        assert_eq!(<f32 as Clamp>::clamp(-5.0, 0.0, 1.0), 0.0);
+        assert_eq!(<f64 as Clamp>::clamp(0.5, 0.0, 1.0), 0.5);
+        assert_eq!(<f64 as Clamp>::clamp01(1.5), 1.0);
+        assert!(<f64 as Clamp>::clamp01(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_min_max() {
+        assert_eq!(3.0_f32.min3(1.0, 2.0), 1.0);
+        assert_eq!(3.0_f64.max3(1.0, 4.0), 4.0);
+        assert_eq!(2.0_f64.min_max(-1.0), (-1.0, 2.0));
+        assert_eq!(f64::NAN.min3(1.0, 2.0), 1.0);
+        let values = [2.0_f64, -3.0, f64::NAN, 7.5, 0.0];
+        assert_eq!(f64::min_of(&values), Some(-3.0));
+        assert_eq!(f64::max_of(&values), Some(7.5));
+        assert_eq!(f64::min_max_of(&values), Some((-3.0, 7.5)));
+        assert_eq!(f32::min_max_of(&[1.0]), Some((1.0, 1.0)));
+        assert_eq!(f32::min_of(&[]), None);
+        assert_eq!(f32::min_max_of(&[]), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_clamp_swapped_bounds() {
+        <f64 as Clamp>::clamp(0.5, 1.0, 0.0);
+    }
+
+    #[test]
+    fn test_lerp() {
+        assert_eq!(2.0_f32.lerp(4.0, 0.0), 2.0);
+        assert_eq!(2.0_f32.lerp(4.0, 1.0), 4.0);
+        assert_eq!(2.0_f64.lerp(4.0, 0.25), 2.5);
+        assert_eq!(2.0_f64.lerp(4.0, 1.5), 5.0);
+        assert_eq!(2.0_f64.lerp(4.0, -0.5), 1.0);
+        assert_eq!(2.0_f64.inverse_lerp(4.0, 2.5), 0.25);
+        assert_eq!(2.0_f64.inverse_lerp(4.0, 5.0), 1.5);
+        assert_eq!(5.0_f64.remap((0.0, 10.0), (100.0, 200.0)), 150.0);
+        assert_eq!(20.0_f64.remap((0.0, 10.0), (100.0, 200.0)), 300.0);
+    }
+
+    #[test]
+    fn test_smoothstep() {
+        assert_eq!((-1.0_f32).smoothstep(0.0, 1.0), 0.0);
+        assert_eq!(0.0_f32.smoothstep(0.0, 1.0), 0.0);
+        assert_eq!(0.5_f32.smoothstep(0.0, 1.0), 0.5);
+        assert_eq!(1.0_f32.smoothstep(0.0, 1.0), 1.0);
+        assert_eq!(2.0_f32.smoothstep(0.0, 1.0), 1.0);
+        assert_eq!(15.0_f64.smoothstep(10.0, 20.0), 0.5);
+        assert_eq!(3.0_f64.smoothstep(2.0, 1.0), 0.0);
+        assert_eq!((-1.0_f64).smootherstep(0.0, 1.0), 0.0);
+        assert_eq!(0.5_f64.smootherstep(0.0, 1.0), 0.5);
+        assert_eq!(2.0_f64.smootherstep(0.0, 1.0), 1.0);
+        assert!(0.25_f64.smootherstep(0.0, 1.0) < 0.25_f64.smoothstep(0.0, 1.0));
+    }
+
+    #[test]
+    fn test_wrap_to_pi() {
+        use core::f64::consts::PI;
+
+        assert_eq!(0.0_f64.wrap_to_pi(), 0.0);
+        assert_eq!(1.0_f64.wrap_to_pi(), 1.0);
+        assert_eq!((-1.0_f64).wrap_to_pi(), -1.0);
+        assert_eq!(PI.wrap_to_pi(), -PI);
+        assert_eq!((-PI).wrap_to_pi(), -PI);
+        assert_eq!((2.0 * PI).wrap_to_pi(), 0.0);
+        assert_eq!((-2.0 * PI).wrap_to_pi(), 0.0);
+        assert_approx_eq!((PI + 0.5).wrap_to_pi(), -PI + 0.5, 1e-15);
+        assert_approx_eq!((-PI - 0.5).wrap_to_pi(), PI - 0.5, 1e-15);
+        assert_approx_eq!((7.0 * PI + 0.25).wrap_to_pi(), -PI + 0.25, 1e-14);
+        assert_eq!(1e-300_f64.wrap_to_pi(), 1e-300);
+        for i in -100..100 {
+            let a = (i as f32 * 0.37).wrap_to_pi();
+            assert!((-core::f32::consts::PI..core::f32::consts::PI).contains(&a));
+        }
+    }
+
+    #[test]
+    fn test_wrap_to_tau() {
+        use core::f64::consts::{PI, TAU};
+
+        assert_eq!(0.0_f64.wrap_to_tau(), 0.0);
+        assert_eq!(PI.wrap_to_tau(), PI);
+        assert_eq!(TAU.wrap_to_tau(), 0.0);
+        assert_eq!((-TAU).wrap_to_tau(), 0.0);
+        assert_approx_eq!((-0.5_f64).wrap_to_tau(), TAU - 0.5);
+        assert_approx_eq!((TAU + 0.5).wrap_to_tau(), 0.5, 1e-15);
+        assert_eq!((-1e-20_f64).wrap_to_tau(), 0.0);
+        assert_eq!((-1e-8_f32).wrap_to_tau(), 0.0);
+        for i in -100..100 {
+            let a = (i as f64 * 0.37).wrap_to_tau();
+            assert!((0.0..TAU).contains(&a));
+        }
+    }
+
+    #[test]
+    fn test_angle_delta() {
+        use core::f64::consts::PI;
+
+        assert_approx_eq!(0.1_f64.angle_delta(0.3), 0.2, 1e-15);
+        assert_approx_eq!(0.3_f64.angle_delta(0.1), -0.2, 1e-15);
+        // Across the ±π seam.
+        assert_approx_eq!((PI - 0.1).angle_delta(-PI + 0.1), 0.2, 1e-15);
+        assert_approx_eq!((-PI + 0.1).angle_delta(PI - 0.1), -0.2, 1e-15);
+        // Across the 0/2π seam.
+        assert_approx_eq!(0.1_f64.angle_delta(2.0 * PI - 0.1), -0.2, 1e-15);
+        assert_eq!(0.0_f64.angle_delta(PI), -PI);
+    }
+
+    #[test]
+    fn test_angle_lerp() {
+        use core::f64::consts::PI;
+
+        assert_approx_eq!(0.0_f64.angle_lerp(1.0, 0.5), 0.5);
+        assert_approx_eq!((PI - 0.1).angle_lerp(-PI + 0.1, 0.5), PI, 1e-15);
+        assert_approx_eq!((PI - 0.1).angle_lerp(-PI + 0.1, 1.0).wrap_to_pi(), -PI + 0.1, 1e-15);
+        assert_approx_eq!(0.1_f32.angle_lerp(-0.1, 0.25), 0.05);
+    }
+
+    #[test]
+    fn test_consts() {
+        assert_eq!(<f32 as Consts>::tau(), <f32 as Radians>::_360());
+        assert_eq!(<f64 as Consts>::pi(), <f64 as Radians>::_180());
+        assert!(<f64 as Consts>::nan().is_nan());
+        assert!(<f32 as Consts>::neg_infinity() < <f32 as Consts>::min_value());
+        assert!(1.0 + <f64 as Consts>::epsilon() > 1.0);
+    }
+
+    #[test]
+    fn test_const() {
+        const RIGHT: f32 = deg_to_rad_f32(90.0);
+        const TABLE: [f64; 3] = [<f64 as ConstZero>::ZERO, <f64 as ConstRadians>::HALF_TURN, rad_to_deg_f64(1.0)];
+        const TURN: Dual<f64> = <Dual<f64> as ConstRadians>::FULL_TURN;
+        assert_eq!(RIGHT, <f32 as ConstRadians>::QUARTER_TURN);
+        assert_eq!(RIGHT, 90.0_f32.deg_to_rad());
+        assert_eq!(TABLE, [0.0, <f64 as Radians>::_180(), 1.0_f64.rad_to_deg()]);
+        assert_eq!((TURN.value, TURN.deriv), (<f64 as Radians>::_360(), 0.0));
+        assert_eq!(<NotNan<f32> as ConstOne>::ONE.into_inner(), 1.0);
+    }
+
+    #[cfg(any(feature = "deterministic", not(feature = "std")))]
+    #[test]
+    fn test_deterministic_golden() {
+        let f64_cases: [(f64, [u64; 3]); 5] = [
+            (1.0, [0x3feaed548f090cee, 0x3fe14a280fb5068c, 0x3ff8eb245cbee3a5]),
+            (2.5, [0x3fe326af0dcfcab0, 0xbfe9a2f7ef858b7d, 0xbfe7e79b4e00bb14]),
+            (-3.0, [0xbfc210386db6d55b, 0xbfefae04be85e5d2, 0x3fc23ef71254b86f]),
+            (100.0, [0xbfe03425b78c4db8, 0x3feb981dbf665fdf, 0xbfe2ca74d62b5d38]),
+            (1e6, [0xbfd6664b2568d867, 0x3fedf9df9906d32c, 0xbfd7e9768ab734c0]),
+        ];
+        for &(x, [sin, cos, tan]) in &f64_cases {
+            assert_eq!(Trig::sin(x).to_bits(), sin);
+            assert_eq!(Trig::cos(x).to_bits(), cos);
+            assert_eq!(Trig::tan(x).to_bits(), tan);
+        }
+        let f32_cases: [(f32, [u32; 3]); 5] = [
+            (1.0, [0x3f576aa4, 0x3f0a5140, 0x3fc75923]),
+            (2.5, [0x3f193578, 0xbf4d17bf, 0xbf3f3cda]),
+            (-3.0, [0xbe1081c3, 0xbf7d7026, 0x3e11f7b9]),
+            (100.0, [0xbf01a12e, 0x3f5cc0ee, 0xbf1653a7]),
+            (1e6, [0xbeb33259, 0x3f6fcefd, 0xbebf4bb4]),
+        ];
+        for &(x, [sin, cos, tan]) in &f32_cases {
+            assert_eq!(Trig::sin(x).to_bits(), sin);
+            assert_eq!(Trig::cos(x).to_bits(), cos);
+            assert_eq!(Trig::tan(x).to_bits(), tan);
+        }
+        assert_eq!(Sqrt::sqrt(3.0_f64).to_bits(), 0x3ffbb67ae8584caa);
+        assert_eq!(Sqrt::sqrt(2.5_f32).to_bits(), 0x3fca62c2);
+        assert_eq!(Powf::powf(2.5_f64, 1.7).to_bits(), 0x4012fdcf53f3e6f3);
+        assert_eq!(Powf::powf(100.0_f32, 1.7).to_bits(), 0x451cfe31);
+        assert_eq!(Trig::atan2(-3.0_f64, 0.7).to_bits(), 0xbff5770c39ba330a);
+        assert_eq!(Trig::atan2(2.5_f32, 0.7).to_bits(), 0x3fa61de8);
+    }
+
+    #[test]
+    fn test_fast_approx() {
+        for i in -100..100 {
+            let x = i as f64 * 0.173;
+            assert!((x.fast_sin() - x.sin()).abs() < 1.1e-3);
+            assert!((x.fast_cos() - x.cos()).abs() < 1.1e-3);
+            assert!(((x as f32).fast_exp() / (x as f32).exp() - 1.0).abs() < 5e-6);
+            assert!((x.fast_atan2(0.5) - x.atan2(0.5)).abs() < 1.2e-5);
+            assert!((x.fast_atan2(-0.5) - x.atan2(-0.5)).abs() < 1.2e-5);
+            let y = (x * 0.1).exp();
+            assert!((y.fast_inv_sqrt() * y.sqrt() - 1.0).abs() < 1.8e-3);
+        }
+        assert_eq!(1e3_f64.fast_exp(), f64::INFINITY);
+        assert_eq!((-1e3_f32).fast_exp(), 0.0);
+        assert!(f32::NAN.fast_sin().is_nan());
+        assert_eq!(0.0_f32.fast_atan2(0.0), 0.0);
+    }
+
+    #[test]
+    fn test_sin_cos() {
+        for i in -50..50 {
+            let x = i as f64 * 0.37;
+            assert_eq!(Trig::sin_cos(x), (Trig::sin(x), Trig::cos(x)));
+            let y = x as f32;
+            assert_eq!(Trig::sin_cos(y), (Trig::sin(y), Trig::cos(y)));
+        }
+        let (s, c) = Ordered(0.5_f64).sin_cos();
+        assert_eq!((s.0, c.0), Trig::sin_cos(0.5_f64));
+    }
+
+    #[test]
+    fn test_sinc() {
+        assert_eq!(Trig::sinc(0.0_f64), 1.0);
+        assert_eq!(Trig::sinc(1e-20_f32), 1.0);
+        assert!((Trig::sinc(-2.0_f64) - Trig::sin(2.0_f64) / 2.0).abs() < 1e-16);
+        for i in 1..100 {
+            // Near the switch to the polynomial, compared with the series to more terms.
+            let x = i as f64 * 3e-6;
+            let x2 = x * x;
+            let series = 1.0 - x2 / 6.0 + x2 * x2 / 120.0 - x2 * x2 * x2 / 5040.0;
+            assert!((Trig::sinc(x) - series).abs() <= 2.0 * f64::EPSILON, "{}", x);
+        }
+        let x = 1e-9_f64;
+        assert!((Trig::cos_m1(x) + x * x / 2.0).abs() < 1e-35);
+        assert_eq!(Trig::cos_m1(0.0_f32), 0.0);
+    }
+
+    #[test]
+    fn test_trig_deg() {
+        assert!((Trig::sin_deg(30.0_f64) - 0.5).abs() < 1e-15);
+        assert!((Trig::cos_deg(60.0_f32) - 0.5).abs() < 1e-6);
+        assert!((Trig::tan_deg(45.0_f64) - 1.0).abs() < 1e-15);
+        assert_eq!(Trig::atan2_deg(1.0_f64, -1.0), 135.0);
+        assert_eq!(Trig::atan2_deg(-1.0_f32, 0.0), -90.0);
+    }
+
+    #[test]
+    fn test_total_order() {
+        let mut v = [f32::NAN, 1.0, -0.0, 0.0, -f32::INFINITY, -f32::NAN];
+        v.sort_unstable_by(|a, b| TotalOrder::total_cmp(*a, *b));
+        assert!(v[0].is_nan() && v[0].is_sign_negative());
+        assert_eq!(v[1], -f32::INFINITY);
+        assert!(v[2] == 0.0 && v[2].is_sign_negative());
+        assert!(v[3] == 0.0 && v[3].is_sign_positive());
+        assert_eq!(v[4], 1.0);
+        assert!(v[5].is_nan());
+        assert!(1.0_f64.max_by_total(f64::NAN).is_nan());
+        assert_eq!(1.0_f64.min_by_total(f64::NAN), 1.0);
+        assert_eq!(TotalOrder::total_cmp(-0.0_f64, 0.0), Ordering::Less);
+    }
+
+    #[test]
+    fn test_bits() {
+        assert_eq!(Bits::to_bits(1.0_f32), 0x3f80_0000);
+        assert_eq!(<f64 as Bits>::from_bits(0x3ff0_0000_0000_0000), 1.0);
+        assert_eq!(Bits::next_up(1.0_f64), 1.0 + f64::EPSILON);
+        assert_eq!(Bits::next_down(1.0_f32), 1.0 - f32::EPSILON / 2.0);
+        assert_eq!(Bits::next_up(-0.0_f32), <f32 as Bits>::from_bits(1));
+        assert_eq!(Bits::next_down(0.0_f64), -<f64 as Bits>::from_bits(1));
+        assert_eq!(Bits::next_up(f64::MAX), f64::INFINITY);
+        assert_eq!(Bits::next_up(f32::NEG_INFINITY), f32::MIN);
+        assert_eq!(Bits::next_up(f32::INFINITY), f32::INFINITY);
+        assert!(Bits::next_down(f64::NAN).is_nan());
+        assert_eq!(Bits::ulp(1.0_f64), f64::EPSILON);
+        assert_eq!(Bits::ulp(-1.0_f32), f32::EPSILON);
+        assert_eq!(Bits::ulp(f64::MAX), Bits::ulp(f64::MAX / 1.5));
+        assert_eq!(Bits::ulp(0.0_f32), <f32 as Bits>::from_bits(1));
+        assert_eq!(Bits::ulp(f32::INFINITY), f32::INFINITY);
+    }
+
+    #[test]
+    fn test_bytes() {
+        fn round_trip<T: Float>(x: T) -> (T, T) {
+            let mut packet = [0; 16];
+            let n = x.to_le_bytes().as_ref().len();
+            packet[..n].copy_from_slice(x.to_le_bytes().as_ref());
+            packet[n..2 * n].copy_from_slice(x.to_be_bytes().as_ref());
+            let (mut le, mut be) = (T::Bytes::default(), T::Bytes::default());
+            le.as_mut().copy_from_slice(&packet[..n]);
+            be.as_mut().copy_from_slice(&packet[n..2 * n]);
+            (T::from_le_bytes(le), T::from_be_bytes(be))
+        }
+
+        assert_eq!(ToBytes::to_le_bytes(1.0_f32), [0, 0, 0x80, 0x3f]);
+        assert_eq!(ToBytes::to_be_bytes(1.0_f32), [0x3f, 0x80, 0, 0]);
+        assert_eq!(round_trip(0.1_f32), (0.1, 0.1));
+        assert_eq!(round_trip(-2.5e300_f64), (-2.5e300, -2.5e300));
+        assert_eq!(round_trip(NotNan::new(3.0_f64).unwrap()).0.into_inner(), 3.0);
+    }
+
+    #[test]
+    fn test_euclid() {
+        assert_eq!(EuclidOps::rem_euclid(-1.0_f32, 16.0), 15.0);
+        assert_eq!(EuclidOps::div_euclid(-1.0_f32, 16.0), -1.0);
+        assert_eq!(EuclidOps::rem_euclid(7.5_f64, -2.0), 1.5);
+        assert_eq!(EuclidOps::div_euclid(7.5_f64, -2.0), -3.0);
+        assert_eq!(EuclidOps::div_euclid(-7.5_f64, -2.0), 4.0);
+        for &(a, b) in &[(-33.25_f64, 8.0), (33.25, -8.0), (-0.5, 0.25), (12.0, 5.0)] {
+            let (q, r) = (EuclidOps::div_euclid(a, b), EuclidOps::rem_euclid(a, b));
+            assert_eq!(b * q + r, a);
+            assert!(r >= 0.0 && r < b.abs());
+        }
+    }
+
+    #[test]
+    fn test_recip_cbrt() {
+        assert_eq!(Recip::recip(4.0_f32), 0.25);
+        assert_eq!(Recip::rsqrt(4.0_f64), 0.5);
+        assert_eq!(Recip::rsqrt(Ordered(16.0_f64)), Ordered(0.25));
+        assert_eq!(Cbrt::cbrt(27.0_f64), 3.0);
+        assert_eq!(Cbrt::cbrt(-8.0_f32), -2.0);
+        assert_eq!(Cbrt::cbrt(f64::NEG_INFINITY), f64::NEG_INFINITY);
+        assert!(Recip::rsqrt(-1.0_f32).is_nan());
+    }
+
+    #[test]
+    fn test_quantize() {
+        assert_eq!(1.0_f32.to_unorm8(), 255);
+        assert_eq!(0.5_f32.to_unorm8(), 128);
+        assert_eq!((-0.5_f64).to_unorm16(), 0);
+        assert_eq!(2.0_f64.to_unorm16(), 65535);
+        assert_eq!(f32::NAN.to_unorm8(), 0);
+        assert_eq!((-1.0_f32).to_snorm8(), -127);
+        assert_eq!((-3.0_f64).to_snorm16(), -32767);
+        assert_eq!(0.5_f32.to_snorm8(), 64);
+        assert_eq!(f64::NAN.to_snorm16(), 0);
+        assert_eq!(f32::from_snorm8(-128), -1.0);
+        assert_eq!(f64::from_snorm16(-32768), -1.0);
+        assert_eq!(f32::from_unorm8(255), 1.0);
+        for v in 0..=u8::MAX {
+            assert_eq!(f32::from_unorm8(v).to_unorm8(), v);
+            assert_eq!(f64::from_snorm8(v as i8).to_snorm8(), (v as i8).max(-127));
+        }
+        for v in 0..=u16::MAX {
+            assert_eq!(f32::from_unorm16(v).to_unorm16(), v);
+            assert_eq!(f32::from_snorm16(v as i16).to_snorm16(), (v as i16).max(-32767));
+        }
+    }
+
+    #[test]
+    fn test_time_cast() {
+        assert_eq!(1.5_f64.to_ticks(60), 90);
+        assert_eq!((1.0_f32 / 60.0 - 1e-6).to_ticks(60), 1);
+        assert_eq!((0.4_f32 / 60.0).to_ticks(60), 0);
+        assert_eq!((-0.5_f64).to_ticks(1000), 0);
+        assert_eq!(f64::NAN.to_ticks(1000), 0);
+        assert_eq!(f64::INFINITY.to_ticks(1000), u64::MAX);
+        assert_eq!(1e30_f32.to_ticks(1), u64::MAX);
+        assert_eq!(f64::from_ticks(90, 60), 1.5);
+        assert_eq!(f32::from_ticks(0, 7), 0.0);
+        // Exact, where converting the tick count to `f64` first would round it.
+        assert_eq!(f64::from_ticks((1 << 53) + 1, 3), 3_002_399_751_580_331.0);
+        for n in 0..1000 {
+            assert_eq!(f64::from_ticks(n, 240).to_ticks(240), n);
+        }
+    }
+
+    #[test]
+    fn test_scalb() {
+        assert_eq!(Scalb::frexp(8.0_f32), (0.5, 4));
+        assert_eq!(Scalb::frexp(-3.0_f64), (-0.75, 2));
+        assert_eq!(Scalb::frexp(f64::MIN_POSITIVE / 4.0), (0.5, -1023));
+        assert_eq!(Scalb::frexp(f32::from_bits(1)), (0.5, -148));
+        assert_eq!(Scalb::frexp(0.0_f32), (0.0, 0));
+        assert_eq!(Scalb::frexp(f64::NEG_INFINITY), (f64::NEG_INFINITY, 0));
+        assert_eq!(Scalb::ldexp(0.75_f64, 3), 6.0);
+        assert_eq!(Scalb::ldexp(1.0_f32, -149), f32::from_bits(1));
+        assert_eq!(Scalb::ldexp(1.0_f32, -150), 0.0);
+        assert_eq!(Scalb::ldexp(f64::MAX, -2000), f64::MAX / 2.0_f64.powi(1023) / 2.0_f64.powi(977));
+        assert_eq!(Scalb::ldexp(f32::from_bits(1), 276), <f32 as Scalb>::exp2i(127));
+        assert_eq!(Scalb::ldexp(1.0_f64, 1024), f64::INFINITY);
+        assert_eq!(<f64 as Scalb>::exp2i(-1074), f64::from_bits(1));
+        assert_eq!(<f32 as Scalb>::exp2i(-10), 1.0 / 1024.0);
+        assert_eq!(Scalb::log2i(1000.0_f64), 9);
+        assert_eq!(Scalb::log2i(-0.25_f32), -2);
+        assert_eq!(Scalb::log2i(0.0_f32), i32::MIN);
+        for &x in &[1.0_f64, -7.5, 1e-310, 3e300, 0.1] {
+            let (m, e) = Scalb::frexp(x);
+            assert!(m.abs() >= 0.5 && m.abs() < 1.0);
+            assert_eq!(Scalb::ldexp(m, e), x);
+        }
+    }
+
+    #[test]
+    fn test_checked() {
+        assert_eq!(1.0_f32.checked_add(2.0), Some(3.0));
+        assert_eq!(f32::MAX.checked_add(f32::MAX), None);
+        assert_eq!(1.0_f64.checked_sub(f64::INFINITY), None);
+        assert_eq!(1e200_f64.checked_mul(1e200), None);
+        assert_eq!(1.0_f64.checked_div(0.0), None);
+        assert_eq!(0.0_f32.checked_div(0.0), None);
+        assert_eq!(1.0_f32.checked_rem(0.0), None);
+        assert_eq!(CheckedOps::checked_sqrt(4.0_f64), Some(2.0));
+        assert_eq!(CheckedOps::checked_sqrt(-1.0_f32), None);
+        assert_eq!((-8.0_f64).checked_powf(1.0 / 3.0), None);
+        assert_eq!(CheckedOps::checked_ln(0.0_f64), None);
+        assert_eq!(f32::NAN.finite_or(0.0), 0.0);
+        assert_eq!(2.0_f64.finite_or(0.0), 2.0);
+        assert_eq!(Ordered(1.0_f64).checked_div(Ordered(0.0)), None);
+    }
+
+    #[test]
+    fn test_midpoint() {
+        assert_eq!(Midpoint::midpoint(1.0_f32, 4.0), 2.5);
+        assert_eq!(Midpoint::midpoint(f64::MAX, f64::MAX), f64::MAX);
+        assert_eq!(Midpoint::midpoint(f32::MAX, -f32::MAX), 0.0);
+        assert_eq!(Midpoint::midpoint(f64::MIN_POSITIVE, 0.0), f64::MIN_POSITIVE / 2.0);
+        assert_eq!(Midpoint::midpoint(f64::from_bits(1), f64::from_bits(3)), f64::from_bits(2));
+        assert_eq!(Midpoint::midpoint(f32::MAX, 1.0), f32::MAX / 2.0);
+        assert_eq!(Midpoint::midpoint(f64::INFINITY, 1.0), f64::INFINITY);
+        assert!(Midpoint::midpoint(f64::INFINITY, f64::NEG_INFINITY).is_nan());
+    }
+
+    #[test]
+    fn test_sign() {
+        assert_eq!(Signed::sign(-2.0_f32), Sign::Negative);
+        assert_eq!(Signed::sign(-0.0_f32), Sign::Zero);
+        assert_eq!(Signed::sign(0.0_f64), Sign::Zero);
+        assert_eq!(Signed::sign(f64::INFINITY), Sign::Positive);
+        assert_eq!(Signed::sign(f32::NAN), Sign::Nan);
+        assert_eq!(Signed::sign(Ordered(-1.0_f64)), Sign::Negative);
+        assert!(Signed::is_sign_negative(-0.0_f64));
+        assert!(Signed::is_sign_positive(0.0_f32));
+        assert!(Signed::is_sign_negative(-f32::NAN));
+        assert!(!Signed::is_sign_positive(f64::NEG_INFINITY));
+    }
+
+    #[test]
+    fn test_range_ops() {
+        assert_eq!(5.0_f32.map_range((0.0, 10.0), (100.0, 200.0), false), 150.0);
+        assert_eq!(15.0_f32.map_range((0.0, 10.0), (100.0, 200.0), false), 250.0);
+        assert_eq!(15.0_f64.map_range((0.0, 10.0), (200.0, 100.0), true), 100.0);
+        assert_eq!(RangeOps::step(0.5_f64, 0.5), 1.0);
+        assert_eq!(RangeOps::step(0.4_f64, 0.5), 0.0);
+        assert_eq!(7.4_f64.quantize(0.5), 7.5);
+        assert_eq!((-7.3_f32).quantize(2.0), -8.0);
+        assert_eq!(3.3_f32.quantize(0.0), 3.3);
+        assert_eq!(0.0_f64.bucket(0.0, 1.0, 4), Some(0));
+        assert_eq!(0.5_f64.bucket(0.0, 1.0, 4), Some(2));
+        assert_eq!(1.0_f64.bucket(0.0, 1.0, 4), Some(3));
+        assert_eq!(1.5_f64.bucket(0.0, 1.0, 4), None);
+        assert_eq!(f32::NAN.bucket(0.0, 1.0, 4), None);
+        assert_eq!(0.5_f32.bucket(0.0, 1.0, 0), None);
+        assert_eq!((-3.0_f32).bucket(-10.0, 10.0, 10), Some(3));
     }
 }
@@ -0,0 +1,1334 @@
+//! Pure Rust floating point math routines.
+//!
+//! Used in place of the `std` methods when the crate is built without `std`,
+//! and for `Sqrt`, `Powf` and `Trig` with the `deterministic` feature.
+//! The `f64` routines follow the algorithms of musl libm (derived from FreeBSD msun).
+//! The `f32` routines evaluate the `f64` routine and round the result.
+//!
+//! Only basic arithmetic and bit manipulation are used,
+//! so the results do not depend on platform intrinsics.
+//! Results are within a few ulps of the correctly rounded value.
+
+#![allow(clippy::approx_constant, clippy::excessive_precision, clippy::unreadable_literal)]
+
+const TOINT: f64 = 6755399441055744.0; // 1.5 / EPSILON
+
+#[inline(always)]
+fn high_word(x: f64) -> u32 { (x.to_bits() >> 32) as u32 }
+
+#[inline(always)]
+fn low_word(x: f64) -> u32 { x.to_bits() as u32 }
+
+#[inline(always)]
+fn with_low_word_cleared(x: f64) -> f64 { f64::from_bits(x.to_bits() & 0xffff_ffff_0000_0000) }
+
+/// Returns `x * 2^n` computed exactly when the result is representable.
+pub fn scalbn(x: f64, mut n: i32) -> f64 {
+    let x1p1023 = f64::from_bits(0x7fe0_0000_0000_0000);
+    let x1p53 = f64::from_bits(0x4340_0000_0000_0000);
+    let x1p_1022 = f64::from_bits(0x0010_0000_0000_0000);
+
+    let mut y = x;
+    if n > 1023 {
+        y *= x1p1023;
+        n -= 1023;
+        if n > 1023 {
+            y *= x1p1023;
+            n -= 1023;
+            if n > 1023 { n = 1023; }
+        }
+    } else if n < -1022 {
+        y *= x1p_1022 * x1p53;
+        n += 1022 - 53;
+        if n < -1022 {
+            y *= x1p_1022 * x1p53;
+            n += 1022 - 53;
+            if n < -1022 { n = -1022; }
+        }
+    }
+    y * f64::from_bits(((0x3ff + n) as u64) << 52)
+}
+
+/// Splits `a * b` into a rounded product and its exact error.
+#[inline]
+fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    const SPLIT: f64 = 134217729.0; // 2^27 + 1
+    let p = a * b;
+    let ca = SPLIT * a;
+    let a_hi = ca - (ca - a);
+    let a_lo = a - a_hi;
+    let cb = SPLIT * b;
+    let b_hi = cb - (cb - b);
+    let b_lo = b - b_hi;
+    let e = ((a_hi * b_hi - p) + a_hi * b_lo + a_lo * b_hi) + a_lo * b_lo;
+    (p, e)
+}
+
+/// Splits a finite nonzero `x` into a 53 bit integer mantissa and exponent.
+#[inline]
+fn decompose(x: f64) -> (u64, i32) {
+    let bits = x.to_bits();
+    let biased = ((bits >> 52) & 0x7ff) as i32;
+    let frac = bits & ((1 << 52) - 1);
+    if biased == 0 {
+        let shift = frac.leading_zeros() - 11;
+        (frac << shift, 1 - shift as i32 - 1075)
+    } else {
+        (frac | (1 << 52), biased - 1075)
+    }
+}
+
+/// Returns `x * y + z` rounded once.
+pub fn fma(x: f64, y: f64, z: f64) -> f64 {
+    if !x.is_finite() || !y.is_finite() || x == 0.0 || y == 0.0 || z == 0.0 {
+        return x * y + z;
+    }
+    if !z.is_finite() { return z; }
+
+    // Both terms as 128 bit integers with the top bit at position 125.
+    let (mx, ex) = decompose(x);
+    let (my, ey) = decompose(y);
+    let p = mx as u128 * my as u128;
+    let p_shift = p.leading_zeros() as i32 - 2;
+    let (p, ep) = (p << p_shift, ex + ey - p_shift);
+    let (mz, ez) = decompose(z);
+    let (zm, ez) = ((mz as u128) << 73, ez - 73);
+
+    let p_neg = x.is_sign_negative() != y.is_sign_negative();
+    let z_neg = z.is_sign_negative();
+    let ((big, e, big_neg), (small, se)) = if ep > ez || (ep == ez && p >= zm) {
+        ((p, ep, p_neg), (zm, ez))
+    } else {
+        ((zm, ez, z_neg), (p, ep))
+    };
+
+    // Align the smaller term, keeping a sticky bit for anything shifted out.
+    let d = (e - se) as u32;
+    let small = if d >= 128 {
+        1
+    } else if d == 0 {
+        small
+    } else {
+        (small >> d) | ((small & ((1 << d) - 1) != 0) as u128)
+    };
+    let r = if p_neg == z_neg { big + small } else { big - small };
+    if r == 0 { return 0.0; }
+
+    // Round to nearest even, down to subnormal precision if needed.
+    let top = 127 - r.leading_zeros() as i32 + e;
+    let lsb = if top - 52 > -1074 { top - 52 } else { -1074 };
+    let shift = lsb - e;
+    let q = if shift <= 0 {
+        r << -shift
+    } else if shift >= 128 {
+        0
+    } else {
+        let q = r >> shift;
+        let rem = r - (q << shift);
+        let half = 1u128 << (shift - 1);
+        if rem > half || (rem == half && q & 1 == 1) { q + 1 } else { q }
+    };
+    let v = scalbn(q as f64, lsb);
+    if big_neg { -v } else { v }
+}
+
+/// Returns `sqrt(x^2 + y^2)` without undue overflow or underflow.
+pub fn hypot(x: f64, y: f64) -> f64 {
+    let (x, y) = (x.abs(), y.abs());
+    if x.is_infinite() || y.is_infinite() { return f64::INFINITY; }
+    if x.is_nan() || y.is_nan() { return f64::NAN; }
+    let (a, b) = if x >= y { (x, y) } else { (y, x) };
+    if b == 0.0 || a > b * f64::from_bits(0x4350_0000_0000_0000) {
+        // b / a < 2^-54
+        return a + b;
+    }
+
+    // Scale a to [1, 2) and sum the squares in double-double precision.
+    let k = decompose(a).1 + 52;
+    let (a, b) = (scalbn(a, -k), scalbn(b, -k));
+    let (ah, al) = two_prod(a, a);
+    let (bh, bl) = two_prod(b, b);
+    let sh = ah + bh;
+    let sl = (ah - sh) + bh + al + bl;
+    let s = sqrt(sh);
+    let (ss, sse) = two_prod(s, s);
+    let r = s + ((sh - ss) - sse + sl) / (2.0 * s);
+    scalbn(r, k)
+}
+
+/// Rounds toward zero.
+pub fn trunc(x: f64) -> f64 {
+    let u = x.to_bits();
+    let mut e = ((u >> 52) & 0x7ff) as i32 - 0x3ff + 12;
+    if e >= 52 + 12 { return x; }
+    if e < 12 { e = 1; }
+    let m = u64::MAX >> e;
+    if u & m == 0 { return x; }
+    f64::from_bits(u & !m)
+}
+
+/// Rounds toward negative infinity.
+pub fn floor(x: f64) -> f64 {
+    let t = trunc(x);
+    if x < 0.0 && t != x { t - 1.0 } else { t }
+}
+
+/// Rounds toward positive infinity.
+pub fn ceil(x: f64) -> f64 {
+    let t = trunc(x);
+    if x > 0.0 && t != x { t + 1.0 } else { t }
+}
+
+/// Rounds to nearest, half-way cases away from zero.
+pub fn round(x: f64) -> f64 {
+    let t = trunc(x);
+    if (x - t).abs() >= 0.5 { t + 1.0_f64.copysign(x) } else { t }
+}
+
+/// Returns the fractional part.
+pub fn fract(x: f64) -> f64 { x - trunc(x) }
+
+/// Correctly rounded square root.
+pub fn sqrt(x: f64) -> f64 {
+    if x.is_nan() || x == 0.0 || x == f64::INFINITY { return x; }
+    if x < 0.0 { return f64::NAN; }
+
+    let (mut m, mut e) = decompose(x);
+    if e & 1 != 0 {
+        m <<= 1;
+        e -= 1;
+    }
+
+    // Integer square root of `m * 2^52`, rounded to nearest.
+    let n = (m as u128) << 52;
+    let mut rem = n;
+    let mut res: u128 = 0;
+    let mut bit: u128 = 1 << 126;
+    while bit > n { bit >>= 2; }
+    while bit != 0 {
+        if rem >= res + bit {
+            rem -= res + bit;
+            res = (res >> 1) + bit;
+        } else {
+            res >>= 1;
+        }
+        bit >>= 2;
+    }
+    if rem > res { res += 1; }
+    scalbn(res as f64, e / 2 - 26)
+}
+
+/// Returns the cube root.
+pub fn cbrt(x: f64) -> f64 {
+    const B1: u32 = 715094163; // (1023 - 1023 / 3 - 0.03306235651) * 2^20
+    const B2: u32 = 696219795; // (1023 - 1023 / 3 - 54 / 3 - 0.03306235651) * 2^20
+    const P0: f64 = 1.87595182427177009643;
+    const P1: f64 = -1.88497979543377169875;
+    const P2: f64 = 1.621429720105354466140;
+    const P3: f64 = -0.758397934778766047437;
+    const P4: f64 = 0.145996192886612446982;
+
+    let mut hx = high_word(x) & 0x7fff_ffff;
+    if hx >= 0x7ff0_0000 { return x + x; }
+
+    // Rough cbrt to 5 bits by dividing the exponent by 3.
+    let mut u = x.to_bits();
+    if hx < 0x0010_0000 {
+        // Zero or subnormal.
+        u = (x * f64::from_bits(0x4350_0000_0000_0000)).to_bits(); // 2^54
+        hx = (u >> 32) as u32 & 0x7fff_ffff;
+        if hx == 0 { return x; }
+        hx = hx / 3 + B2;
+    } else {
+        hx = hx / 3 + B1;
+    }
+    u &= 1 << 63;
+    u |= (hx as u64) << 32;
+    let mut t = f64::from_bits(u);
+
+    // Refine to 23 bits with a polynomial in `t^3 / x`.
+    let r = (t * t) * (t / x);
+    t *= (P0 + r * (P1 + r * P2)) + ((r * r) * r) * (P3 + r * P4);
+
+    // Round to 23 bits so `t * t` is exact, then do one Newton step to 53 bits.
+    t = f64::from_bits((t.to_bits() + 0x8000_0000) & 0xffff_ffff_c000_0000);
+    let s = t * t;
+    let r = x / s;
+    let w = t + t;
+    let r = (r - t) / (w + r);
+    t + t * r
+}
+
+const LN2_HI: f64 = 6.93147180369123816490e-01; // 0x3fe62e42_fee00000
+const LN2_LO: f64 = 1.90821492927058770002e-10; // 0x3dea39ef_35793c76
+const LN_2_TAIL: f64 = 2.319046813846299558e-17; // ln(2) - LN_2
+const INV_LN2: f64 = 1.44269504088896338700e+00; // 0x3ff71547_652b82fe
+
+/// Computes `e^(x + tail)` where `tail` is tiny compared to `x`.
+fn exp_tail(x: f64, tail: f64) -> f64 {
+    const P1: f64 = 1.66666666666666019037e-01;
+    const P2: f64 = -2.77777777770155933842e-03;
+    const P3: f64 = 6.61375632143793436117e-05;
+    const P4: f64 = -1.65339022054652515390e-06;
+    const P5: f64 = 4.13813679705723846039e-08;
+
+    if x.is_nan() { return x; }
+    if x > 709.782712893383973096 { return x * f64::from_bits(0x7fe0_0000_0000_0000); }
+    if x < -745.13321910194110842 { return 0.0; }
+
+    let hx = high_word(x) & 0x7fff_ffff;
+    let (hi, lo, k) = if hx > 0x3fd6_2e42 {
+        // |x| > 0.5 ln2
+        let k = if hx >= 0x3ff0_a2b2 {
+            // |x| >= 1.5 ln2
+            (INV_LN2 * x + 0.5_f64.copysign(x)) as i32
+        } else if x < 0.0 { -1 } else { 1 };
+        let kf = k as f64;
+        (x - kf * LN2_HI, kf * LN2_LO - tail, k)
+    } else if hx > 0x3e30_0000 {
+        // |x| > 2^-28
+        (x, -tail, 0)
+    } else {
+        return 1.0 + (x + tail);
+    };
+
+    let r = hi - lo;
+    let rr = r * r;
+    let c = r - rr * (P1 + rr * (P2 + rr * (P3 + rr * (P4 + rr * P5))));
+    let y = 1.0 + (r * c / (2.0 - c) - lo + hi);
+    if k == 0 { y } else { scalbn(y, k) }
+}
+
+/// Returns `e^x`.
+pub fn exp(x: f64) -> f64 { exp_tail(x, 0.0) }
+
+/// Returns `2^x`.
+pub fn exp2(x: f64) -> f64 {
+    if x.is_nan() { return x; }
+    if x >= 1024.0 { return f64::INFINITY; }
+    if x < -1075.0 { return 0.0; }
+    let k = round(x);
+    let r = x - k;
+    let (p, e) = two_prod(r, core::f64::consts::LN_2);
+    scalbn(exp_tail(p, e + r * LN_2_TAIL), k as i32)
+}
+
+/// Returns `e^x - 1`.
+pub fn expm1(x: f64) -> f64 {
+    if x.is_nan() || x == f64::INFINITY { return x; }
+    if x.abs() < f64::from_bits(0x3c90_0000_0000_0000) { return x; } // 2^-54
+    if x < -40.0 { return -1.0; }
+    let u = exp(x);
+    if u == 1.0 { return x; }
+    let um1 = u - 1.0;
+    if um1 == -1.0 { return -1.0; }
+    if u == f64::INFINITY { return u; }
+    um1 * (x / log(u))
+}
+
+/// Reduces `x` to `2^k * (1 + f)` with `sqrt(2)/2 < 1 + f < sqrt(2)`.
+///
+/// Returns `Err` with the result when the logarithm is trivial (zero, negative, infinite or NaN input).
+fn log_reduce(x: f64) -> Result<(i32, f64), f64> {
+    let mut x = x;
+    let mut ui = x.to_bits();
+    let mut hx = (ui >> 32) as u32;
+    let mut k = 0;
+    if hx < 0x0010_0000 || (hx >> 31) != 0 {
+        if ui << 1 == 0 { return Err(f64::NEG_INFINITY); }
+        if hx >> 31 != 0 { return Err(f64::NAN); }
+        // Subnormal, scale up.
+        k -= 54;
+        x *= f64::from_bits(0x4350_0000_0000_0000);
+        ui = x.to_bits();
+        hx = (ui >> 32) as u32;
+    } else if hx >= 0x7ff0_0000 {
+        return Err(x);
+    } else if hx == 0x3ff0_0000 && ui << 32 == 0 {
+        return Err(0.0);
+    }
+
+    hx += 0x3ff0_0000 - 0x3fe6_a09e;
+    k += (hx >> 20) as i32 - 0x3ff;
+    hx = (hx & 0x000f_ffff) + 0x3fe6_a09e;
+    ui = ((hx as u64) << 32) | (ui & 0xffff_ffff);
+    Ok((k, f64::from_bits(ui) - 1.0))
+}
+
+/// Returns `(s, hfsq, r)` for the logarithm kernel of `1 + f`.
+#[inline]
+fn log_kernel(f: f64) -> (f64, f64, f64) {
+    const LG1: f64 = 6.666666666666735130e-01;
+    const LG2: f64 = 3.999999999940941908e-01;
+    const LG3: f64 = 2.857142874366239149e-01;
+    const LG4: f64 = 2.222219843214978396e-01;
+    const LG5: f64 = 1.818357216161805012e-01;
+    const LG6: f64 = 1.531383769920937332e-01;
+    const LG7: f64 = 1.479819860511658591e-01;
+
+    let hfsq = 0.5 * f * f;
+    let s = f / (2.0 + f);
+    let z = s * s;
+    let w = z * z;
+    let t1 = w * (LG2 + w * (LG4 + w * LG6));
+    let t2 = z * (LG1 + w * (LG3 + w * (LG5 + w * LG7)));
+    (s, hfsq, t2 + t1)
+}
+
+/// Returns the logarithm of `1 + f` as an unevaluated sum `hi + lo`.
+#[inline]
+fn log_kernel_split(f: f64) -> (f64, f64) {
+    let (s, hfsq, r) = log_kernel(f);
+    let hi = with_low_word_cleared(f - hfsq);
+    let lo = f - hi - hfsq + s * (hfsq + r);
+    (hi, lo)
+}
+
+/// Returns the natural logarithm.
+pub fn log(x: f64) -> f64 {
+    let (k, f) = match log_reduce(x) {
+        Ok(v) => v,
+        Err(v) => return v,
+    };
+    let (s, hfsq, r) = log_kernel(f);
+    let dk = k as f64;
+    s * (hfsq + r) + dk * LN2_LO - hfsq + f + dk * LN2_HI
+}
+
+/// Returns the base 2 logarithm.
+pub fn log2(x: f64) -> f64 {
+    const IVLN2HI: f64 = 1.44269504072144627571e+00;
+    const IVLN2LO: f64 = 1.67517131648865118353e-10;
+
+    let (k, f) = match log_reduce(x) {
+        Ok(v) => v,
+        Err(v) => return v,
+    };
+    let (hi, lo) = log_kernel_split(f);
+    let mut val_hi = hi * IVLN2HI;
+    let mut val_lo = (lo + hi) * IVLN2LO + lo * IVLN2HI;
+    let y = k as f64;
+    let w = y + val_hi;
+    val_lo += (y - w) + val_hi;
+    val_hi = w;
+    val_lo + val_hi
+}
+
+/// Returns the base 10 logarithm.
+pub fn log10(x: f64) -> f64 {
+    const IVLN10HI: f64 = 4.34294481878168880939e-01;
+    const IVLN10LO: f64 = 2.50829467116452752298e-11;
+    const LOG10_2HI: f64 = 3.01029995663611771306e-01;
+    const LOG10_2LO: f64 = 3.69423907715893078616e-13;
+
+    let (k, f) = match log_reduce(x) {
+        Ok(v) => v,
+        Err(v) => return v,
+    };
+    let (hi, lo) = log_kernel_split(f);
+    let dk = k as f64;
+    let mut val_hi = hi * IVLN10HI;
+    let y = dk * LOG10_2HI;
+    let mut val_lo = dk * LOG10_2LO + (lo + hi) * IVLN10LO + lo * IVLN10HI;
+    let w = y + val_hi;
+    val_lo += (y - w) + val_hi;
+    val_hi = w;
+    val_lo + val_hi
+}
+
+/// Returns `ln(1 + x)`.
+pub fn log1p(x: f64) -> f64 {
+    if x.is_nan() || x == f64::INFINITY { return x; }
+    let u = 1.0 + x;
+    if u == 1.0 { return x; }
+    log(u) * (x / (u - 1.0))
+}
+
+/// Returns `x^y`.
+pub fn pow(x: f64, y: f64) -> f64 {
+    if y == 0.0 || x == 1.0 { return 1.0; }
+    if x.is_nan() || y.is_nan() { return x + y; }
+
+    let y_is_int = trunc(y) == y;
+    let y_is_odd = y_is_int && y.abs() < 9007199254740992.0 && (y as i64) & 1 != 0;
+
+    if y.is_infinite() {
+        let ax = x.abs();
+        return if ax == 1.0 {
+            1.0
+        } else if (ax < 1.0) == (y > 0.0) {
+            0.0
+        } else {
+            f64::INFINITY
+        };
+    }
+    if x == 0.0 || x.is_infinite() {
+        // Magnitude is either zero or infinity.
+        let big = (x == 0.0) == (y < 0.0);
+        let r = if big { f64::INFINITY } else { 0.0 };
+        return if y_is_odd && x.is_sign_negative() { -r } else { r };
+    }
+
+    let mut sign = 1.0;
+    let mut ax = x;
+    if x < 0.0 {
+        if !y_is_int { return f64::NAN; }
+        if y_is_odd { sign = -1.0; }
+        ax = -x;
+    }
+
+    // ln(ax) as double-double.
+    let (k, f) = match log_reduce(ax) {
+        Ok(v) => v,
+        // Only reached for `ax == 1`.
+        Err(_) => return sign,
+    };
+    let (hi, lo) = log_kernel_split(f);
+    let dk = k as f64;
+    let a = dk * LN2_HI;
+    let l_hi = a + hi;
+    let l_lo = (a - l_hi) + hi + (lo + dk * LN2_LO);
+    let t = l_hi + l_lo;
+    let l_lo = l_lo - (t - l_hi);
+    let l_hi = t;
+
+    // Settle results far outside the exponent range first, since splitting a huge `y`
+    // in `two_prod` overflows. As `|l_hi| > 1e-16` here, this bounds `|y|` below `1e20`.
+    let estimate = y * l_hi;
+    if estimate > 1500.0 { return sign * f64::INFINITY; }
+    if estimate < -1500.0 { return sign * 0.0; }
+    let (p_hi, p_err) = two_prod(y, l_hi);
+    let p_lo = p_err + y * l_lo;
+    let p = p_hi + p_lo;
+    let p_lo = p_lo - (p - p_hi);
+    let p_hi = p;
+
+    if p_hi > 709.8 { return sign * f64::INFINITY; }
+    if p_hi < -745.2 { return sign * 0.0; }
+    sign * exp_tail(p_hi, p_lo)
+}
+
+/// Returns `x^n` by repeated multiplication.
+pub fn powi(x: f64, n: i32) -> f64 {
+    let mut base = x;
+    let mut e = n.unsigned_abs();
+    let mut r = 1.0;
+    while e != 0 {
+        if e & 1 != 0 { r *= base; }
+        base *= base;
+        e >>= 1;
+    }
+    if n < 0 { 1.0 / r } else { r }
+}
+
+const PIO2_HI: f64 = 1.57079632679489655800e+00; // 0x3ff921fb_54442d18
+const PIO2_LO: f64 = 6.12323399573676603587e-17; // 0x3c91a626_33145c07
+
+/// Bits of 2/π, most significant first.
+const TWO_OVER_PI: [u64; 20] = [
+    0xA2F9836E4E441529,
+    0xFC2757D1F534DDC0,
+    0xDB6295993C439041,
+    0xFE5163ABDEBBC561,
+    0xB7246E3A424DD2E0,
+    0x06492EEA09D1921C,
+    0xFE1DEB1CB129A73E,
+    0xE88235F52EBB4484,
+    0xE99C7026B45F7E41,
+    0x3991D639835339F4,
+    0x9C845F8BBDF9283B,
+    0x1FF897FFDE05980F,
+    0xEF2F118B5A0A6D1F,
+    0x6D367ECF27CB09B7,
+    0x4F463F669E5FEA2D,
+    0x7527BAC7EBE5F17B,
+    0x3D0739F78A5292EA,
+    0x6BFB5FB11F8D5D08,
+    0x56033046FC7B6BAB,
+    0xF0CFBC209AF4361D,
+];
+
+/// Returns 64 bits of 2/π starting at bit `offset` (0 is the first bit after the point).
+#[inline]
+fn two_over_pi_bits(offset: usize) -> u64 {
+    let w = offset / 64;
+    let b = offset % 64;
+    if b == 0 {
+        TWO_OVER_PI[w]
+    } else {
+        (TWO_OVER_PI[w] << b) | (TWO_OVER_PI[w + 1] >> (64 - b))
+    }
+}
+
+/// Payne-Hanek reduction of a large positive finite `x`.
+fn rem_pio2_large(x: f64) -> (i32, f64, f64) {
+    let bits = x.to_bits();
+    let m = (bits & ((1 << 52) - 1)) | (1 << 52);
+    let e = ((bits >> 52) & 0x7ff) as i32 - 1075;
+
+    // Window of 192 bits of 2/π; bits before it only contribute multiples of 4.
+    let start = if e >= 3 { (e - 2) as usize } else { 1 };
+    let w = [
+        two_over_pi_bits(start - 1),
+        two_over_pi_bits(start - 1 + 64),
+        two_over_pi_bits(start - 1 + 128),
+    ];
+    let frac_bits = (start as i32 + 191 - e) as u32;
+
+    // 256 bit product m * w, least significant limb first.
+    let mut p = [0u64; 4];
+    let mut carry: u128 = 0;
+    for i in 0..3 {
+        let t = (m as u128) * (w[2 - i] as u128) + carry;
+        p[i] = t as u64;
+        carry = t >> 64;
+    }
+    p[3] = carry as u64;
+
+    // 128 bit fixed point value with 2 integer bits.
+    let sh = frac_bits - 126;
+    let lo = (p[0] as u128) | ((p[1] as u128) << 64);
+    let hi = (p[2] as u128) | ((p[3] as u128) << 64);
+    let q = (lo >> sh) | (hi << (128 - sh));
+
+    let q = q.wrapping_add(1 << 125);
+    let n = (q >> 126) as i32 & 3;
+    let frac = (q & ((1 << 126) - 1)) as i128 - (1 << 125);
+
+    let scale = f64::from_bits(((1023 - 126) as u64) << 52);
+    let f_hi = frac as f64;
+    let f_lo = (frac - f_hi as i128) as f64;
+    let f_hi = f_hi * scale;
+    let f_lo = f_lo * scale;
+
+    let (r, e) = two_prod(f_hi, PIO2_HI);
+    let e = e + f_hi * PIO2_LO + f_lo * PIO2_HI;
+    let y0 = r + e;
+    let y1 = e - (y0 - r);
+    (n, y0, y1)
+}
+
+/// Returns `(n, y0, y1)` with `x = n * π/2 + y0 + y1` and `|y0| <= π/4` approximately.
+fn rem_pio2(x: f64) -> (i32, f64, f64) {
+    const INVPIO2: f64 = 6.36619772367581382433e-01;
+    const PIO2_1: f64 = 1.57079632673412561417e+00;
+    const PIO2_1T: f64 = 6.07710050650619224932e-11;
+    const PIO2_2: f64 = 6.07710050630396597660e-11;
+    const PIO2_2T: f64 = 2.02226624879595063154e-21;
+    const PIO2_3: f64 = 2.02226624871116645580e-21;
+    const PIO2_3T: f64 = 8.47842766036889956997e-32;
+
+    let ix = high_word(x) & 0x7fff_ffff;
+    if ix < 0x4139_21fb {
+        // |x| ~< 2^20 * π/2
+        let fnv = (x * INVPIO2 + TOINT) - TOINT;
+        let n = fnv as i32;
+        let mut r = x - fnv * PIO2_1;
+        let mut w = fnv * PIO2_1T;
+        let mut y0 = r - w;
+        let ex = (ix >> 20) as i32;
+        let ey = ((high_word(y0) >> 20) & 0x7ff) as i32;
+        if ex - ey > 16 {
+            let t = r;
+            w = fnv * PIO2_2;
+            r = t - w;
+            w = fnv * PIO2_2T - ((t - r) - w);
+            y0 = r - w;
+            let ey = ((high_word(y0) >> 20) & 0x7ff) as i32;
+            if ex - ey > 49 {
+                let t = r;
+                w = fnv * PIO2_3;
+                r = t - w;
+                w = fnv * PIO2_3T - ((t - r) - w);
+                y0 = r - w;
+            }
+        }
+        let y1 = (r - y0) - w;
+        return (n, y0, y1);
+    }
+
+    let (n, y0, y1) = rem_pio2_large(x.abs());
+    if x < 0.0 { (-n, -y0, -y1) } else { (n, y0, y1) }
+}
+
+/// Sine kernel on `[-π/4, π/4]`, `y` is the tail of `x`.
+fn k_sin(x: f64, y: f64) -> f64 {
+    const S1: f64 = -1.66666666666666324348e-01;
+    const S2: f64 = 8.33333333332248946124e-03;
+    const S3: f64 = -1.98412698298579493134e-04;
+    const S4: f64 = 2.75573137070700676789e-06;
+    const S5: f64 = -2.50507602534068634195e-08;
+    const S6: f64 = 1.58969099521155010221e-10;
+
+    let z = x * x;
+    let w = z * z;
+    let r = S2 + z * (S3 + z * S4) + z * w * (S5 + z * S6);
+    let v = z * x;
+    x - ((z * (0.5 * y - v * r) - y) - v * S1)
+}
+
+/// Cosine kernel on `[-π/4, π/4]`, `y` is the tail of `x`.
+fn k_cos(x: f64, y: f64) -> f64 {
+    const C1: f64 = 4.16666666666666019037e-02;
+    const C2: f64 = -1.38888888888741095749e-03;
+    const C3: f64 = 2.48015872894767294178e-05;
+    const C4: f64 = -2.75573143513906633035e-07;
+    const C5: f64 = 2.08757232129817482790e-09;
+    const C6: f64 = -1.13596475577881948265e-11;
+
+    let z = x * x;
+    let w = z * z;
+    let r = z * (C1 + z * (C2 + z * C3)) + w * w * (C4 + z * (C5 + z * C6));
+    let hz = 0.5 * z;
+    let w = 1.0 - hz;
+    w + (((1.0 - w) - hz) + (z * r - x * y))
+}
+
+/// Returns the sine.
+pub fn sin(x: f64) -> f64 {
+    let ix = high_word(x) & 0x7fff_ffff;
+    if ix <= 0x3fe9_21fb {
+        if ix < 0x3e50_0000 { return x; }
+        return k_sin(x, 0.0);
+    }
+    if ix >= 0x7ff0_0000 { return f64::NAN; }
+    let (n, y0, y1) = rem_pio2(x);
+    match n & 3 {
+        0 => k_sin(y0, y1),
+        1 => k_cos(y0, y1),
+        2 => -k_sin(y0, y1),
+        _ => -k_cos(y0, y1),
+    }
+}
+
+/// Returns the cosine.
+pub fn cos(x: f64) -> f64 {
+    let ix = high_word(x) & 0x7fff_ffff;
+    if ix <= 0x3fe9_21fb {
+        if ix < 0x3e46_a09e { return 1.0; }
+        return k_cos(x, 0.0);
+    }
+    if ix >= 0x7ff0_0000 { return f64::NAN; }
+    let (n, y0, y1) = rem_pio2(x);
+    match n & 3 {
+        0 => k_cos(y0, y1),
+        1 => -k_sin(y0, y1),
+        2 => -k_cos(y0, y1),
+        _ => k_sin(y0, y1),
+    }
+}
+
+/// Returns the sine and cosine, sharing the argument reduction.
+pub fn sincos(x: f64) -> (f64, f64) {
+    let ix = high_word(x) & 0x7fff_ffff;
+    if ix <= 0x3fe9_21fb {
+        let s = if ix < 0x3e50_0000 { x } else { k_sin(x, 0.0) };
+        let c = if ix < 0x3e46_a09e { 1.0 } else { k_cos(x, 0.0) };
+        return (s, c);
+    }
+    if ix >= 0x7ff0_0000 { return (f64::NAN, f64::NAN); }
+    let (n, y0, y1) = rem_pio2(x);
+    let (s, c) = (k_sin(y0, y1), k_cos(y0, y1));
+    match n & 3 {
+        0 => (s, c),
+        1 => (c, -s),
+        2 => (-s, -c),
+        _ => (-c, s),
+    }
+}
+
+/// Returns the tangent.
+pub fn tan(x: f64) -> f64 {
+    let ix = high_word(x) & 0x7fff_ffff;
+    if ix <= 0x3fe9_21fb {
+        if ix < 0x3e40_0000 { return x; }
+        return k_sin(x, 0.0) / k_cos(x, 0.0);
+    }
+    if ix >= 0x7ff0_0000 { return f64::NAN; }
+    let (n, y0, y1) = rem_pio2(x);
+    let s = k_sin(y0, y1);
+    let c = k_cos(y0, y1);
+    if n & 1 == 0 { s / c } else { -c / s }
+}
+
+/// Rational approximation used by `asin` and `acos`.
+fn asin_r(z: f64) -> f64 {
+    const PS0: f64 = 1.66666666666666657415e-01;
+    const PS1: f64 = -3.25565818622400915405e-01;
+    const PS2: f64 = 2.01212532134862925881e-01;
+    const PS3: f64 = -4.00555345006794114027e-02;
+    const PS4: f64 = 7.91534994289814532176e-04;
+    const PS5: f64 = 3.47933107596021167570e-05;
+    const QS1: f64 = -2.40339491173441421878e+00;
+    const QS2: f64 = 2.02094576023350569471e+00;
+    const QS3: f64 = -6.88283971605453293030e-01;
+    const QS4: f64 = 7.70381505559019352791e-02;
+
+    let p = z * (PS0 + z * (PS1 + z * (PS2 + z * (PS3 + z * (PS4 + z * PS5)))));
+    let q = 1.0 + z * (QS1 + z * (QS2 + z * (QS3 + z * QS4)));
+    p / q
+}
+
+/// Returns the arcsine.
+pub fn asin(x: f64) -> f64 {
+    let hx = high_word(x);
+    let ix = hx & 0x7fff_ffff;
+    if ix >= 0x3ff0_0000 {
+        // |x| >= 1 or NaN
+        if (ix - 0x3ff0_0000) | low_word(x) == 0 { return x * PIO2_HI; }
+        return f64::NAN + x;
+    }
+    if ix < 0x3fe0_0000 {
+        // |x| < 0.5
+        if ix < 0x3e50_0000 { return x; }
+        return x + x * asin_r(x * x);
+    }
+    let z = (1.0 - x.abs()) * 0.5;
+    let s = sqrt(z);
+    let r = asin_r(z);
+    let y = if ix >= 0x3fef_3333 {
+        // |x| > 0.975
+        PIO2_HI - (2.0 * (s + s * r) - PIO2_LO)
+    } else {
+        let f = with_low_word_cleared(s);
+        let c = (z - f * f) / (s + f);
+        0.5 * PIO2_HI - (2.0 * s * r - (PIO2_LO - 2.0 * c) - (0.5 * PIO2_HI - 2.0 * f))
+    };
+    if hx >> 31 != 0 { -y } else { y }
+}
+
+/// Returns the arccosine.
+pub fn acos(x: f64) -> f64 {
+    let hx = high_word(x);
+    let ix = hx & 0x7fff_ffff;
+    if ix >= 0x3ff0_0000 {
+        // |x| >= 1 or NaN
+        if (ix - 0x3ff0_0000) | low_word(x) == 0 {
+            return if hx >> 31 != 0 { 2.0 * PIO2_HI } else { 0.0 };
+        }
+        return f64::NAN + x;
+    }
+    if ix < 0x3fe0_0000 {
+        // |x| < 0.5
+        if ix <= 0x3c60_0000 { return PIO2_HI; }
+        return PIO2_HI - (x - (PIO2_LO - x * asin_r(x * x)));
+    }
+    if hx >> 31 != 0 {
+        // x < -0.5
+        let z = (1.0 + x) * 0.5;
+        let s = sqrt(z);
+        let w = asin_r(z) * s - PIO2_LO;
+        return 2.0 * (PIO2_HI - (s + w));
+    }
+    // x > 0.5
+    let z = (1.0 - x) * 0.5;
+    let s = sqrt(z);
+    let df = with_low_word_cleared(s);
+    let c = (z - df * df) / (s + df);
+    let w = asin_r(z) * s + c;
+    2.0 * (df + w)
+}
+
+/// Returns the arctangent.
+pub fn atan(x: f64) -> f64 {
+    const ATANHI: [f64; 4] = [
+        4.63647609000806093515e-01,
+        7.85398163397448278999e-01,
+        9.82793723247329054082e-01,
+        1.57079632679489655800e+00,
+    ];
+    const ATANLO: [f64; 4] = [
+        2.26987774529616870924e-17,
+        3.06161699786838301793e-17,
+        1.39033110312309984516e-17,
+        6.12323399573676603587e-17,
+    ];
+    const AT: [f64; 11] = [
+        3.33333333333329318027e-01,
+        -1.99999999998764832476e-01,
+        1.42857142725034663711e-01,
+        -1.11111104054623557880e-01,
+        9.09088713343650656196e-02,
+        -7.69187620504482999495e-02,
+        6.66107313738753120669e-02,
+        -5.83357013379057348645e-02,
+        4.97687799461593236017e-02,
+        -3.65315727442169155270e-02,
+        1.62858201153657823623e-02,
+    ];
+
+    let negative = x.is_sign_negative();
+    let ix = high_word(x) & 0x7fff_ffff;
+    if ix >= 0x4410_0000 {
+        // |x| >= 2^66
+        if x.is_nan() { return x; }
+        return if negative { -ATANHI[3] } else { ATANHI[3] };
+    }
+    let mut x = x;
+    let id: i32;
+    if ix < 0x3fdc_0000 {
+        // |x| < 0.4375
+        if ix < 0x3e40_0000 { return x; }
+        id = -1;
+    } else {
+        x = x.abs();
+        if ix < 0x3ff3_0000 {
+            // |x| < 1.1875
+            if ix < 0x3fe6_0000 {
+                // 7/16 <= |x| < 11/16
+                id = 0;
+                x = (2.0 * x - 1.0) / (2.0 + x);
+            } else {
+                // 11/16 <= |x| < 19/16
+                id = 1;
+                x = (x - 1.0) / (x + 1.0);
+            }
+        } else if ix < 0x4003_8000 {
+            // |x| < 2.4375
+            id = 2;
+            x = (x - 1.5) / (1.0 + 1.5 * x);
+        } else {
+            // 2.4375 <= |x| < 2^66
+            id = 3;
+            x = -1.0 / x;
+        }
+    }
+    let z = x * x;
+    let w = z * z;
+    let s1 = z * (AT[0] + w * (AT[2] + w * (AT[4] + w * (AT[6] + w * (AT[8] + w * AT[10])))));
+    let s2 = w * (AT[1] + w * (AT[3] + w * (AT[5] + w * (AT[7] + w * AT[9]))));
+    if id < 0 { return x - x * (s1 + s2); }
+    let id = id as usize;
+    let z = ATANHI[id] - ((x * (s1 + s2) - ATANLO[id]) - x);
+    if negative { -z } else { z }
+}
+
+/// Returns the four quadrant arctangent of `y` and `x`.
+pub fn atan2(y: f64, x: f64) -> f64 {
+    const PI: f64 = 3.1415926535897931160e+00;
+    const PI_LO: f64 = 1.2246467991473531772e-16;
+
+    if x.is_nan() || y.is_nan() { return x + y; }
+    if x == 1.0 { return atan(y); }
+    let m = (y.is_sign_negative() as u32) | ((x.is_sign_negative() as u32) << 1);
+    let ix = high_word(x) & 0x7fff_ffff;
+    let iy = high_word(y) & 0x7fff_ffff;
+
+    if y == 0.0 {
+        return match m {
+            0 | 1 => y,
+            2 => PI,
+            _ => -PI,
+        };
+    }
+    if x == 0.0 {
+        return if m & 1 != 0 { -PI / 2.0 } else { PI / 2.0 };
+    }
+    if x.is_infinite() {
+        if y.is_infinite() {
+            return match m {
+                0 => PI / 4.0,
+                1 => -PI / 4.0,
+                2 => 3.0 * PI / 4.0,
+                _ => -3.0 * PI / 4.0,
+            };
+        }
+        return match m {
+            0 => 0.0,
+            1 => -0.0,
+            2 => PI,
+            _ => -PI,
+        };
+    }
+    // |y/x| > 2^64
+    if ix + (64 << 20) < iy || y.is_infinite() {
+        return if m & 1 != 0 { -PI / 2.0 } else { PI / 2.0 };
+    }
+    let z = if m & 2 != 0 && iy + (64 << 20) < ix {
+        // |y/x| < 2^-64, x < 0
+        0.0
+    } else {
+        atan((y / x).abs())
+    };
+    match m {
+        0 => z,
+        1 => -z,
+        2 => PI - (z - PI_LO),
+        _ => (z - PI_LO) - PI,
+    }
+}
+
+/// Returns `exp(x) / 2` for large `x`, with `sign` applied, without overflowing early.
+fn expo2(x: f64, sign: f64) -> f64 {
+    const K: i32 = 2043;
+    let kln2 = f64::from_bits(0x4096_2066_151a_dd8b);
+    let scale = f64::from_bits(((0x3ff + K / 2) as u64) << 52);
+    exp(x - kln2) * (sign * scale) * scale
+}
+
+/// Returns the hyperbolic sine.
+pub fn sinh(x: f64) -> f64 {
+    let h = 0.5_f64.copysign(x);
+    let absx = x.abs();
+    let w = high_word(absx);
+    if w < 0x4086_2e42 {
+        // |x| < log(MAX)
+        let t = expm1(absx);
+        if w < 0x3ff0_0000 {
+            if w < 0x3ff0_0000 - (26 << 20) { return x; }
+            return h * (2.0 * t - t * t / (t + 1.0));
+        }
+        return h * (t + t / (t + 1.0));
+    }
+    expo2(absx, 2.0 * h)
+}
+
+/// Returns the hyperbolic cosine.
+pub fn cosh(x: f64) -> f64 {
+    let absx = x.abs();
+    let w = high_word(absx);
+    if w < 0x3fe6_2e42 {
+        // |x| < ln2
+        if w < 0x3ff0_0000 - (26 << 20) { return 1.0; }
+        let t = expm1(absx);
+        return 1.0 + t * t / (2.0 * (1.0 + t));
+    }
+    if w < 0x4086_2e42 {
+        // |x| < log(MAX)
+        let t = exp(absx);
+        return 0.5 * (t + 1.0 / t);
+    }
+    expo2(absx, 1.0)
+}
+
+/// Returns the hyperbolic tangent.
+pub fn tanh(x: f64) -> f64 {
+    let absx = x.abs();
+    let w = high_word(absx);
+    let t = if w > 0x3fe1_93ea {
+        // |x| > log(3)/2
+        if w > 0x4034_0000 {
+            // |x| > 20 or NaN
+            1.0 - 0.0 / absx
+        } else {
+            1.0 - 2.0 / (expm1(2.0 * absx) + 2.0)
+        }
+    } else if w > 0x3fd0_58ae {
+        // |x| > log(5/3)/2
+        let t = expm1(2.0 * absx);
+        t / (t + 2.0)
+    } else if w >= 0x0010_0000 {
+        let t = expm1(-2.0 * absx);
+        -t / (t + 2.0)
+    } else {
+        absx
+    };
+    if x.is_sign_negative() { -t } else { t }
+}
+
+/// Returns the inverse hyperbolic sine.
+pub fn asinh(x: f64) -> f64 {
+    let e = ((x.to_bits() >> 52) & 0x7ff) as u32;
+    let absx = x.abs();
+    let y = if e >= 0x3ff + 26 {
+        // |x| >= 2^26 or inf or nan
+        log(absx) + core::f64::consts::LN_2
+    } else if e > 0x3ff {
+        // |x| >= 2
+        log(2.0 * absx + 1.0 / (sqrt(absx * absx + 1.0) + absx))
+    } else if e >= 0x3ff - 26 {
+        // |x| >= 2^-26
+        log1p(absx + absx * absx / (sqrt(absx * absx + 1.0) + 1.0))
+    } else {
+        return x;
+    };
+    if x.is_sign_negative() { -y } else { y }
+}
+
+/// Returns the inverse hyperbolic cosine.
+pub fn acosh(x: f64) -> f64 {
+    let e = ((x.to_bits() >> 52) & 0x7ff) as u32;
+    if e < 0x3ff + 1 {
+        // |x| < 2
+        let t = x - 1.0;
+        return log1p(t + sqrt(t * t + 2.0 * t));
+    }
+    if e < 0x3ff + 26 {
+        // |x| < 2^26
+        return log(2.0 * x - 1.0 / (x + sqrt(x * x - 1.0)));
+    }
+    log(x) + core::f64::consts::LN_2
+}
+
+/// Returns the inverse hyperbolic tangent.
+pub fn atanh(x: f64) -> f64 {
+    let e = ((x.to_bits() >> 52) & 0x7ff) as u32;
+    let y = x.abs();
+    let y = if e < 0x3ff - 1 {
+        if e < 0x3ff - 32 {
+            y
+        } else {
+            // |x| < 0.5
+            0.5 * log1p(2.0 * y + 2.0 * y * y / (1.0 - y))
+        }
+    } else {
+        0.5 * log1p(2.0 * (y / (1.0 - y)))
+    };
+    if x.is_sign_negative() { -y } else { y }
+}
+
+macro_rules! via_f64 {
+    ($($name:ident => $f64_name:ident;)*) => {
+        $(
+            #[inline]
+            pub fn $name(x: f32) -> f32 { $f64_name(x as f64) as f32 }
+        )*
+    };
+}
+
+via_f64! {
+    truncf => trunc;
+    floorf => floor;
+    ceilf => ceil;
+    roundf => round;
+    fractf => fract;
+    sqrtf => sqrt;
+    cbrtf => cbrt;
+    expf => exp;
+    exp2f => exp2;
+    expm1f => expm1;
+    logf => log;
+    log2f => log2;
+    log10f => log10;
+    log1pf => log1p;
+    sinf => sin;
+    cosf => cos;
+    tanf => tan;
+    asinf => asin;
+    acosf => acos;
+    atanf => atan;
+    sinhf => sinh;
+    coshf => cosh;
+    tanhf => tanh;
+    asinhf => asinh;
+    acoshf => acosh;
+    atanhf => atanh;
+}
+
+/// Returns the sine and cosine, sharing the argument reduction.
+#[inline]
+pub fn sincosf(x: f32) -> (f32, f32) {
+    let (s, c) = sincos(x as f64);
+    (s as f32, c as f32)
+}
+
+/// Returns `x^y`.
+#[inline]
+pub fn powf(x: f32, y: f32) -> f32 { pow(x as f64, y as f64) as f32 }
+
+/// Returns `x^n` by repeated multiplication.
+pub fn powif(x: f32, n: i32) -> f32 {
+    let mut base = x;
+    let mut e = n.unsigned_abs();
+    let mut r = 1.0;
+    while e != 0 {
+        if e & 1 != 0 { r *= base; }
+        base *= base;
+        e >>= 1;
+    }
+    if n < 0 { 1.0 / r } else { r }
+}
+
+/// Returns `sqrt(x^2 + y^2)`.
+#[inline]
+pub fn hypotf(x: f32, y: f32) -> f32 {
+    let (x, y) = (x as f64, y as f64);
+    if x.is_infinite() || y.is_infinite() { return f32::INFINITY; }
+    sqrt(x * x + y * y) as f32
+}
+
+/// Returns `x * y + z` rounded once.
+pub fn fmaf(x: f32, y: f32, z: f32) -> f32 {
+    // The product is exact in `f64`, so only the sum is rounded.
+    let xy = x as f64 * y as f64;
+    let z = z as f64;
+    let r = xy + z;
+    let u = r.to_bits();
+    if u & 0x1fff_ffff == 0x1000_0000 && r.is_finite() {
+        // Half-way between two `f32`, so use the rounding error to break the tie.
+        let bv = r - xy;
+        let err = (xy - (r - bv)) + (z - bv);
+        if err != 0.0 {
+            let u = if (err > 0.0) == (r > 0.0) { u + 1 } else { u - 1 };
+            return f64::from_bits(u) as f32;
+        }
+    }
+    r as f32
+}
+
+/// Returns the four quadrant arctangent of `y` and `x`.
+#[inline]
+pub fn atan2f(y: f32, x: f32) -> f32 { atan2(y as f64, x as f64) as f32 }
+
+
+#[cfg(all(test, feature = "std"))]
+mod test {
+    use super::*;
+
+    fn assert_close(name: &str, x: f64, a: f64, b: f64, max_ulps: f64) {
+        if a.is_nan() && b.is_nan() || a == b { return; }
+        let err = (a - b).abs() / (b.abs().max(f64::MIN_POSITIVE) * f64::EPSILON);
+        assert!(err <= max_ulps, "{}({:e}) = {:e}, expected {:e}", name, x, a, b);
+    }
+
+    fn samples(lo: f64, hi: f64) -> impl Iterator<Item = f64> {
+        (0..=1000).map(move |i| lo + (hi - lo) * i as f64 / 1000.0)
+    }
+
+    #[test]
+    fn test_unary_f64() {
+        type Case = (&'static str, fn(f64) -> f64, fn(f64) -> f64, f64, f64);
+        let fs: [Case; 26] = [
+            ("trunc", trunc, f64::trunc, -1e6, 1e6),
+            ("floor", floor, f64::floor, -1e6, 1e6),
+            ("ceil", ceil, f64::ceil, -1e6, 1e6),
+            ("round", round, f64::round, -1e3, 1e3),
+            ("fract", fract, f64::fract, -1e3, 1e3),
+            ("sqrt", sqrt, f64::sqrt, 0.0, 1e6),
+            ("cbrt", cbrt, f64::cbrt, -1e6, 1e6),
+            ("exp", exp, f64::exp, -745.0, 709.0),
+            ("exp2", exp2, f64::exp2, -1074.0, 1023.0),
+            ("expm1", expm1, f64::exp_m1, -3.0, 3.0),
+            ("log", log, f64::ln, 1e-3, 1e6),
+            ("log2", log2, f64::log2, 1e-3, 1e6),
+            ("log10", log10, f64::log10, 1e-3, 1e6),
+            ("log1p", log1p, f64::ln_1p, -0.99, 10.0),
+            ("sin", sin, f64::sin, -1e6, 1e6),
+            ("cos", cos, f64::cos, -1e6, 1e6),
+            ("tan", tan, f64::tan, -10.0, 10.0),
+            ("asin", asin, f64::asin, -1.0, 1.0),
+            ("acos", acos, f64::acos, -1.0, 1.0),
+            ("atan", atan, f64::atan, -10.0, 10.0),
+            ("sinh", sinh, f64::sinh, -700.0, 700.0),
+            ("cosh", cosh, f64::cosh, -700.0, 700.0),
+            ("tanh", tanh, f64::tanh, -30.0, 30.0),
+            ("asinh", asinh, f64::asinh, -1e6, 1e6),
+            ("acosh", acosh, f64::acosh, 2.0, 1e6),
+            ("atanh", atanh, f64::atanh, -0.5, 0.5),
+        ];
+        for &(name, f, g, lo, hi) in fs.iter() {
+            for x in samples(lo, hi) {
+                assert_close(name, x, f(x), g(x), 3.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_sincos() {
+        for x in samples(-1e4, 1e4).chain(samples(-1e-7, 1e-7)) {
+            let (s, c) = sincos(x);
+            assert_eq!(s, sin(x));
+            assert_eq!(c, cos(x));
+        }
+        assert!(sincos(f64::INFINITY).0.is_nan());
+        assert_eq!(sincos(-0.0).0.to_bits(), (-0.0_f64).to_bits());
+    }
+
+    #[test]
+    fn test_large_trig_reduction() {
+        for &x in [1e7, 1e22, 1e300, -3.0e150, f64::MAX].iter() {
+            assert_close("sin", x, sin(x), x.sin(), 1.0);
+            assert_close("cos", x, cos(x), x.cos(), 1.0);
+        }
+    }
+
+    #[test]
+    fn test_pow() {
+        for x in samples(0.01, 100.0) {
+            for y in samples(-20.0, 20.0).step_by(50) {
+                assert_close("pow", x, pow(x, y), x.powf(y), 3.0);
+            }
+        }
+        assert_eq!(pow(2.0, 10.0), 1024.0);
+        assert_eq!(pow(-2.0, 3.0), -8.0);
+        assert!(pow(-2.0, 0.5).is_nan());
+        assert_eq!(pow(0.0, -1.0), f64::INFINITY);
+        assert_eq!(pow(f64::NAN, 0.0), 1.0);
+        // Huge exponents, where splitting `y` would overflow.
+        assert_eq!(pow(2.0, 1e305), f64::INFINITY);
+        assert_eq!(pow(0.5, 1e305), 0.0);
+        assert_eq!(pow(2.0, -1e305), 0.0);
+        assert_eq!(pow(-3.0, 2e304), f64::INFINITY);
+        assert_eq!(pow(1.0 + f64::EPSILON, 1e300), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_powi() {
+        for x in samples(-3.0, 3.0).step_by(13) {
+            for n in -20..20 {
+                assert_close("powi", x, powi(x, n), x.powi(n), 16.0);
+                assert_eq!(powif(x as f32, n), (x as f32).powi(n));
+            }
+        }
+    }
+
+    #[test]
+    fn test_atan2() {
+        for y in samples(-10.0, 10.0).step_by(10) {
+            for x in samples(-10.0, 10.0).step_by(10) {
+                assert_close("atan2", y, atan2(y, x), y.atan2(x), 2.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fma() {
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            f64::from_bits((state & 0x800f_ffff_ffff_ffff) | ((0x3c0 + (state >> 52) % 128) << 52))
+        };
+        for _ in 0..10000 {
+            let (x, y, z) = (next(), next(), next());
+            assert_eq!(fma(x, y, z), x.mul_add(y, z));
+            assert_eq!(fma(x, y, -(x * y)), x.mul_add(y, -(x * y)));
+            let (a, b, c) = (x as f32, y as f32, z as f32);
+            assert_eq!(fmaf(a, b, c), a.mul_add(b, c));
+        }
+        assert_eq!(fma(1e-300, 1e-300, 1e-320), 1e-300_f64.mul_add(1e-300, 1e-320));
+        assert_eq!(fma(1e300, 1e300, 1.0), f64::INFINITY);
+        assert_eq!(fma(2.0, 3.0, -6.0), 0.0);
+        assert!(fma(f64::INFINITY, 0.0, 1.0).is_nan());
+    }
+
+    #[test]
+    fn test_hypot() {
+        for x in samples(-10.0, 10.0).step_by(7) {
+            for y in samples(-1e3, 1e3).step_by(11) {
+                assert_close("hypot", x, hypot(x, y), x.hypot(y), 1.0);
+                assert_eq!(hypotf(x as f32, y as f32), (x as f32).hypot(y as f32));
+            }
+        }
+        assert_eq!(hypot(3e300, 4e300), 5e300);
+        assert_eq!(hypot(3e-310, 4e-310), 5e-310);
+        assert_eq!(hypot(f64::NAN, f64::INFINITY), f64::INFINITY);
+        assert!(hypot(f64::NAN, 1.0).is_nan());
+    }
+
+    #[test]
+    fn test_f32() {
+        fn assert_close_f32(a: f32, b: f32) {
+            assert!((a - b).abs() <= b.abs() * f32::EPSILON, "{} != {}", a, b);
+        }
+        for i in 0..=100 {
+            let x = i as f32 * 0.05 - 2.5;
+            assert_close_f32(sinf(x), x.sin());
+            assert_close_f32(cosf(x), x.cos());
+            assert_close_f32(expf(x), x.exp());
+            assert_close_f32(atan2f(x, 1.5), x.atan2(1.5));
+            assert_close_f32(powf(x.abs(), 1.5), x.abs().powf(1.5));
+        }
+    }
+}
@@ -0,0 +1,117 @@
+//! Floating point math routines forwarded to `std`.
+//!
+//! Mirrors the function names of the bundled `libm` module,
+//! so trait impls can call either without knowing which one is enabled.
+
+macro_rules! forward {
+    ($($name:ident => $t:ident::$method:ident;)*) => {
+        $(
+            #[inline(always)]
+            pub fn $name(x: $t) -> $t { x.$method() }
+        )*
+    };
+}
+
+forward! {
+    truncf => f32::trunc;
+    floorf => f32::floor;
+    ceilf => f32::ceil;
+    roundf => f32::round;
+    fractf => f32::fract;
+    sqrtf => f32::sqrt;
+    cbrtf => f32::cbrt;
+    expf => f32::exp;
+    exp2f => f32::exp2;
+    expm1f => f32::exp_m1;
+    logf => f32::ln;
+    log2f => f32::log2;
+    log10f => f32::log10;
+    log1pf => f32::ln_1p;
+    sinf => f32::sin;
+    cosf => f32::cos;
+    tanf => f32::tan;
+    asinf => f32::asin;
+    acosf => f32::acos;
+    atanf => f32::atan;
+    sinhf => f32::sinh;
+    coshf => f32::cosh;
+    tanhf => f32::tanh;
+    asinhf => f32::asinh;
+    acoshf => f32::acosh;
+    atanhf => f32::atanh;
+
+    trunc => f64::trunc;
+    floor => f64::floor;
+    ceil => f64::ceil;
+    round => f64::round;
+    fract => f64::fract;
+    sqrt => f64::sqrt;
+    cbrt => f64::cbrt;
+    exp => f64::exp;
+    exp2 => f64::exp2;
+    expm1 => f64::exp_m1;
+    log => f64::ln;
+    log2 => f64::log2;
+    log10 => f64::log10;
+    log1p => f64::ln_1p;
+    sin => f64::sin;
+    cos => f64::cos;
+    tan => f64::tan;
+    asin => f64::asin;
+    acos => f64::acos;
+    atan => f64::atan;
+    sinh => f64::sinh;
+    cosh => f64::cosh;
+    tanh => f64::tanh;
+    asinh => f64::asinh;
+    acosh => f64::acosh;
+    atanh => f64::atanh;
+}
+
+/// Returns the sine and cosine.
+#[inline(always)]
+pub fn sincosf(x: f32) -> (f32, f32) { x.sin_cos() }
+
+/// Returns the sine and cosine.
+#[inline(always)]
+pub fn sincos(x: f64) -> (f64, f64) { x.sin_cos() }
+
+/// Returns `x^y`.
+#[inline(always)]
+pub fn powf(x: f32, y: f32) -> f32 { x.powf(y) }
+
+/// Returns `x^y`.
+#[inline(always)]
+pub fn pow(x: f64, y: f64) -> f64 { x.powf(y) }
+
+/// Returns `x^n`.
+#[inline(always)]
+pub fn powif(x: f32, n: i32) -> f32 { x.powi(n) }
+
+/// Returns `x^n`.
+#[inline(always)]
+pub fn powi(x: f64, n: i32) -> f64 { x.powi(n) }
+
+/// Returns the four quadrant arctangent of `y` and `x`.
+#[inline(always)]
+pub fn atan2f(y: f32, x: f32) -> f32 { y.atan2(x) }
+
+/// Returns the four quadrant arctangent of `y` and `x`.
+#[inline(always)]
+pub fn atan2(y: f64, x: f64) -> f64 { y.atan2(x) }
+
+/// Returns `x * y + z` rounded once.
+#[inline(always)]
+pub fn fmaf(x: f32, y: f32, z: f32) -> f32 { x.mul_add(y, z) }
+
+/// Returns `x * y + z` rounded once.
+#[inline(always)]
+pub fn fma(x: f64, y: f64, z: f64) -> f64 { x.mul_add(y, z) }
+
+/// Returns `sqrt(x^2 + y^2)`.
+#[inline(always)]
+pub fn hypotf(x: f32, y: f32) -> f32 { x.hypot(y) }
+
+/// Returns `sqrt(x^2 + y^2)`.
+#[inline(always)]
+pub fn hypot(x: f64, y: f64) -> f64 { x.hypot(y) }
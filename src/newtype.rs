@@ -0,0 +1,334 @@
+//! Forwarding float traits to newtype wrappers.
+
+/// Implements every trait required by `Float` for a tuple struct wrapping a float,
+/// by delegating to the inner field.
+///
+/// The struct must derive or implement `Clone`, `Copy`, `PartialEq` and `PartialOrd`.
+///
+/// ```
+/// #[macro_use]
+/// extern crate float;
+///
+/// use float::{Float, Sqrt};
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+/// struct Meters(f64);
+///
+/// #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+/// struct Seconds(f32);
+///
+/// float_impl!(Meters(f64), Seconds(f32));
+///
+/// fn half<T: Float>(x: T) -> T { x / T::from_f64(2.0) }
+///
+/// # fn main() {
+/// assert_eq!(half(Meters(3.0)), Meters(1.5));
+/// assert_eq!(half(Seconds(1.0)).sqrt(), Seconds(0.5_f32.sqrt()));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! float_impl {
+    ($($name:ident($inner:ty)),* $(,)*) => {
+        $(
+            $crate::float_impl!(@impl [] $name, $inner, $name);
+        )*
+    };
+    (@impl [$($gen:tt)*] $ty:ty, $inner:ty, $wrap:path) => {
+        $crate::float_impl!(@impl [$($gen)*] $ty, $inner, $wrap, |v| Ok($wrap(v)));
+    };
+    // `$parse` wraps a parsed value, and may reject it where `$wrap` would panic.
+    (@impl [$($gen:tt)*] $ty:ty, $inner:ty, $wrap:path, $parse:expr) => {
+        $crate::float_impl!(@op [$($gen)*] $ty, $wrap: Add::add, AddAssign::add_assign);
+        $crate::float_impl!(@op [$($gen)*] $ty, $wrap: Sub::sub, SubAssign::sub_assign);
+        $crate::float_impl!(@op [$($gen)*] $ty, $wrap: Mul::mul, MulAssign::mul_assign);
+        $crate::float_impl!(@op [$($gen)*] $ty, $wrap: Div::div, DivAssign::div_assign);
+        $crate::float_impl!(@op [$($gen)*] $ty, $wrap: Rem::rem, RemAssign::rem_assign);
+
+        impl<$($gen)*> $crate::__core::ops::Neg for $ty {
+            type Output = Self;
+            #[inline(always)]
+            fn neg(self) -> Self { $wrap(-self.0) }
+        }
+
+        impl<$($gen)*> $crate::Min for $ty {
+            $crate::float_impl!(@binary $wrap, Min: min);
+        }
+
+        impl<$($gen)*> $crate::Max for $ty {
+            $crate::float_impl!(@binary $wrap, Max: max);
+        }
+
+        impl<$($gen)*> $crate::Midpoint for $ty {
+            $crate::float_impl!(@binary $wrap, Midpoint: midpoint);
+        }
+
+        impl<$($gen)*> $crate::Clamp for $ty {
+            #[inline(always)]
+            fn clamp(self, min: Self, max: Self) -> Self {
+                $wrap($crate::Clamp::clamp(self.0, min.0, max.0))
+            }
+            $crate::float_impl!(@unary $wrap, Clamp: clamp01);
+        }
+
+        impl<$($gen)*> $crate::Signum for $ty {
+            $crate::float_impl!(@unary $wrap, Signum: signum);
+        }
+
+        impl<$($gen)*> $crate::Abs for $ty {
+            $crate::float_impl!(@unary $wrap, Abs: abs);
+            $crate::float_impl!(@binary $wrap, Abs: copysign, hypot);
+        }
+
+        impl<$($gen)*> $crate::Powf for $ty {
+            $crate::float_impl!(@binary $wrap, Powf: powf);
+        }
+
+        impl<$($gen)*> $crate::Powi for $ty {
+            #[inline(always)]
+            fn powi(self, n: i32) -> Self { $wrap($crate::Powi::powi(self.0, n)) }
+            $crate::float_impl!(@unary $wrap, Powi: sqr, cube);
+        }
+
+        impl<$($gen)*> $crate::MulAdd for $ty {
+            #[inline(always)]
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                $wrap($crate::MulAdd::mul_add(self.0, a.0, b.0))
+            }
+        }
+
+        impl<$($gen)*> $crate::Exp for $ty {
+            $crate::float_impl!(@unary $wrap, Exp: exp, exp2, exp_m1);
+        }
+
+        impl<$($gen)*> $crate::Log for $ty {
+            $crate::float_impl!(@unary $wrap, Log: ln, log2, log10, ln_1p);
+            $crate::float_impl!(@binary $wrap, Log: log);
+        }
+
+        impl<$($gen)*> $crate::Round for $ty {
+            $crate::float_impl!(@unary $wrap, Round: floor, ceil, round, trunc, fract);
+        }
+
+        impl<$($gen)*> $crate::EuclidOps for $ty {
+            $crate::float_impl!(@binary $wrap, EuclidOps: div_euclid, rem_euclid);
+        }
+
+        impl<$($gen)*> $crate::Classify for $ty {
+            #[inline(always)]
+            fn is_nan(self) -> bool { $crate::Classify::is_nan(self.0) }
+
+            #[inline(always)]
+            fn is_infinite(self) -> bool { $crate::Classify::is_infinite(self.0) }
+
+            #[inline(always)]
+            fn is_finite(self) -> bool { $crate::Classify::is_finite(self.0) }
+
+            #[inline(always)]
+            fn is_normal(self) -> bool { $crate::Classify::is_normal(self.0) }
+
+            #[inline(always)]
+            fn classify(self) -> $crate::__core::num::FpCategory {
+                $crate::Classify::classify(self.0)
+            }
+        }
+
+        impl<$($gen)*> $crate::ApproxEq for $ty {
+            #[inline(always)]
+            fn approx_eq(self, other: Self, epsilon: Self) -> bool {
+                $crate::ApproxEq::approx_eq(self.0, other.0, epsilon.0)
+            }
+
+            #[inline(always)]
+            fn ulps_eq(self, other: Self, max_ulps: u32) -> bool {
+                $crate::ApproxEq::ulps_eq(self.0, other.0, max_ulps)
+            }
+        }
+
+        impl<$($gen)*> $crate::TotalOrder for $ty {
+            #[inline(always)]
+            fn total_cmp(self, other: Self) -> $crate::__core::cmp::Ordering {
+                $crate::TotalOrder::total_cmp(self.0, other.0)
+            }
+        }
+
+        impl<$($gen)*> $crate::ToBytes for $ty {
+            type Bytes = <$inner as $crate::ToBytes>::Bytes;
+
+            #[inline(always)]
+            fn to_le_bytes(self) -> Self::Bytes { $crate::ToBytes::to_le_bytes(self.0) }
+
+            #[inline(always)]
+            fn to_be_bytes(self) -> Self::Bytes { $crate::ToBytes::to_be_bytes(self.0) }
+        }
+
+        impl<$($gen)*> $crate::FromBytes for $ty {
+            #[inline(always)]
+            fn from_le_bytes(bytes: Self::Bytes) -> Self {
+                $wrap(<$inner as $crate::FromBytes>::from_le_bytes(bytes))
+            }
+
+            #[inline(always)]
+            fn from_be_bytes(bytes: Self::Bytes) -> Self {
+                $wrap(<$inner as $crate::FromBytes>::from_be_bytes(bytes))
+            }
+        }
+
+        impl<$($gen)*> $crate::ParseFloat for $ty {
+            #[inline(always)]
+            fn from_str(s: &str) -> Result<Self, $crate::ParseFloatError> {
+                <$inner as $crate::ParseFloat>::from_str(s).and_then($parse)
+            }
+        }
+
+        impl<$($gen)*> $crate::FormatFloat for $ty {
+            #[inline(always)]
+            fn write_with_precision<W: $crate::__core::fmt::Write>(
+                self,
+                w: &mut W,
+                digits: usize,
+            ) -> $crate::__core::fmt::Result {
+                $crate::FormatFloat::write_with_precision(self.0, w, digits)
+            }
+        }
+
+        impl<$($gen)*> $crate::Radians for $ty {
+            $crate::float_impl!(@constant $wrap, $inner, Radians: _90, _180, _360);
+            $crate::float_impl!(@unary $wrap, Radians: deg_to_rad, rad_to_deg);
+        }
+
+        impl<$($gen)*> $crate::Consts for $ty {
+            $crate::float_impl!(@constant $wrap, $inner, Consts: pi, tau, e, epsilon, min_value,
+                                max_value, min_positive, infinity, neg_infinity, nan);
+        }
+
+        impl<$($gen)*> $crate::One for $ty {
+            $crate::float_impl!(@constant $wrap, $inner, One: one);
+        }
+
+        impl<$($gen)*> $crate::Zero for $ty {
+            $crate::float_impl!(@constant $wrap, $inner, Zero: zero);
+        }
+
+        impl<$($gen)*> $crate::ConstRadians for $ty where $inner: $crate::ConstRadians {
+            $crate::float_impl!(@const $inner, ConstRadians: QUARTER_TURN, HALF_TURN, FULL_TURN);
+        }
+
+        impl<$($gen)*> $crate::ConstOne for $ty where $inner: $crate::ConstOne {
+            $crate::float_impl!(@const $inner, ConstOne: ONE);
+        }
+
+        impl<$($gen)*> $crate::ConstZero for $ty where $inner: $crate::ConstZero {
+            $crate::float_impl!(@const $inner, ConstZero: ZERO);
+        }
+
+        impl<$($gen)*> $crate::Sqrt for $ty {
+            $crate::float_impl!(@unary $wrap, Sqrt: sqrt);
+        }
+
+        impl<$($gen)*> $crate::Recip for $ty {
+            $crate::float_impl!(@unary $wrap, Recip: recip, rsqrt);
+        }
+
+        impl<$($gen)*> $crate::Cbrt for $ty {
+            $crate::float_impl!(@unary $wrap, Cbrt: cbrt);
+        }
+
+        impl<$($gen)*> $crate::Trig for $ty {
+            $crate::float_impl!(@unary $wrap, Trig: sin, cos, tan, asin, acos, atan,
+                                sinh, cosh, tanh, asinh, acosh, atanh);
+            $crate::float_impl!(@binary $wrap, Trig: atan2);
+
+            #[inline(always)]
+            fn sin_cos(self) -> (Self, Self) {
+                let (s, c) = $crate::Trig::sin_cos(self.0);
+                ($wrap(s), $wrap(c))
+            }
+        }
+
+        impl<$($gen)*> $crate::FromPrimitive for $ty {
+            $crate::float_impl!(@from $wrap, $inner: from_f64(f64), from_f32(f32), from_isize(isize),
+                                from_u32(u32), from_i32(i32), from_u64(u64), from_i64(i64),
+                                from_usize(usize), from_u8(u8), from_i8(i8), from_u16(u16),
+                                from_i16(i16));
+        }
+
+        impl<$($gen)*> $crate::ToPrimitive for $ty {
+            $crate::float_impl!(@to to_f64 -> f64, to_f32 -> f32, to_i64 -> i64, to_u64 -> u64,
+                                to_i32 -> i32, to_u32 -> u32, to_isize -> isize, to_usize -> usize,
+                                checked_to_i64 -> Option<i64>, checked_to_u64 -> Option<u64>,
+                                checked_to_i32 -> Option<i32>, checked_to_u32 -> Option<u32>,
+                                checked_to_isize -> Option<isize>, checked_to_usize -> Option<usize>);
+        }
+    };
+    (@op [$($gen:tt)*] $ty:ty, $wrap:path: $op:ident::$f:ident, $op_assign:ident::$f_assign:ident) => {
+        impl<$($gen)*> $crate::__core::ops::$op for $ty {
+            type Output = Self;
+            #[inline(always)]
+            fn $f(self, other: Self) -> Self { $wrap($crate::__core::ops::$op::$f(self.0, other.0)) }
+        }
+
+        impl<$($gen)*> $crate::__core::ops::$op_assign for $ty {
+            #[inline(always)]
+            fn $f_assign(&mut self, other: Self) { *self = $crate::__core::ops::$op::$f(*self, other) }
+        }
+    };
+    (@unary $wrap:path, $tr:ident: $($name:ident),*) => {
+        $(
+            #[inline(always)]
+            fn $name(self) -> Self { $wrap($crate::$tr::$name(self.0)) }
+        )*
+    };
+    (@binary $wrap:path, $tr:ident: $($name:ident),*) => {
+        $(
+            #[inline(always)]
+            fn $name(self, other: Self) -> Self { $wrap($crate::$tr::$name(self.0, other.0)) }
+        )*
+    };
+    (@const $inner:ty, $tr:ident: $($name:ident),*) => {
+        // The tuple constructor rather than `$wrap`, which need not be a `const fn`.
+        $(
+            const $name: Self = Self(<$inner as $crate::$tr>::$name);
+        )*
+    };
+    (@constant $wrap:path, $inner:ty, $tr:ident: $($name:ident),*) => {
+        $(
+            #[inline(always)]
+            fn $name() -> Self { $wrap(<$inner as $crate::$tr>::$name()) }
+        )*
+    };
+    (@from $wrap:path, $inner:ty: $($name:ident($t:ty)),*) => {
+        $(
+            #[inline(always)]
+            fn $name(t: $t) -> Self { $wrap(<$inner as $crate::FromPrimitive>::$name(t)) }
+        )*
+    };
+    (@to $($name:ident -> $t:ty),*) => {
+        $(
+            #[inline(always)]
+            fn $name(self) -> $t { $crate::ToPrimitive::$name(self.0) }
+        )*
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use {Classify, ConstZero, Consts, Float, FromPrimitive, ToPrimitive, Trig};
+
+    #[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+    struct Meters(f64);
+
+    float_impl!(Meters(f64));
+
+    fn distance<T: Float>(x: T, y: T) -> T { (x * x + y * y).sqrt() }
+
+    #[test]
+    fn test_newtype() {
+        assert_eq!(distance(Meters(3.0), Meters(4.0)), Meters(5.0));
+        assert_eq!(-Meters(1.0) % Meters(0.75), Meters(-0.25));
+        assert_eq!(Meters::from_f32(0.5), Meters(0.5));
+        assert_eq!(Meters(2.5).to_i32(), 2);
+        assert_eq!(Meters(0.0).sin_cos(), (Meters(0.0), Meters(1.0)));
+        assert!(Meters::nan().is_nan());
+        assert_eq!(Meters::ZERO, Meters(0.0));
+    }
+}
@@ -0,0 +1,257 @@
+//! Coherent noise for terrain, textures and particles.
+//!
+//! Every function is deterministic for a given `seed`,
+//! continuous in its coordinates and returns values in `[-1, 1]`.
+//! The gradient noises, `perlin*` and `simplex*`, are zero at integer lattice points.
+
+use {Float, Lerp};
+
+/// Returns 1D value noise, interpolating random values at the integers.
+pub fn value1<T: Float>(x: T, seed: u32) -> T {
+    let (i, fx) = split(x);
+    let v = |di| unit::<T>(hash(seed, &[i.wrapping_add(di)]));
+    v(0).lerp(v(1), fade(fx))
+}
+
+/// Returns 2D value noise, interpolating random values on the integer lattice.
+pub fn value2<T: Float>(x: T, y: T, seed: u32) -> T {
+    let ((i, fx), (j, fy)) = (split(x), split(y));
+    let v = |di, dj| unit::<T>(hash(seed, &[i.wrapping_add(di), j.wrapping_add(dj)]));
+    let (u, w) = (fade(fx), fade(fy));
+    v(0, 0).lerp(v(1, 0), u).lerp(v(0, 1).lerp(v(1, 1), u), w)
+}
+
+/// Returns 3D value noise, interpolating random values on the integer lattice.
+pub fn value3<T: Float>(x: T, y: T, z: T, seed: u32) -> T {
+    let ((i, fx), (j, fy), (k, fz)) = (split(x), split(y), split(z));
+    let v = |di, dj, dk| {
+        unit::<T>(hash(seed, &[i.wrapping_add(di), j.wrapping_add(dj), k.wrapping_add(dk)]))
+    };
+    let (u, w, s) = (fade(fx), fade(fy), fade(fz));
+    let plane = |dk| v(0, 0, dk).lerp(v(1, 0, dk), u).lerp(v(0, 1, dk).lerp(v(1, 1, dk), u), w);
+    plane(0).lerp(plane(1), s)
+}
+
+/// Returns 1D Perlin gradient noise.
+pub fn perlin1<T: Float>(x: T, seed: u32) -> T {
+    let (i, fx) = split(x);
+    let g = |di: i32, dx: T| unit::<T>(hash(seed, &[i.wrapping_add(di)])) * dx;
+    // The unscaled noise peaks at 0.5 halfway between lattice points.
+    let two = T::from_f64(2.0);
+    two * g(0, fx).lerp(g(1, fx - T::one()), fade(fx))
+}
+
+/// Returns 2D Perlin gradient noise.
+pub fn perlin2<T: Float>(x: T, y: T, seed: u32) -> T {
+    let ((i, fx), (j, fy)) = (split(x), split(y));
+    let g = |di: i32, dj: i32| {
+        let h = hash(seed, &[i.wrapping_add(di), j.wrapping_add(dj)]);
+        let (gx, gy) = GRAD2[h as usize % 8];
+        let (dx, dy) = (fx - T::from_i32(di), fy - T::from_i32(dj));
+        T::from_f64(gx) * dx + T::from_f64(gy) * dy
+    };
+    let (u, w) = (fade(fx), fade(fy));
+    let n = g(0, 0).lerp(g(1, 0), u).lerp(g(0, 1).lerp(g(1, 1), u), w);
+    // The unscaled noise peaks at `sqrt(2) / 2`.
+    n * T::from_f64(::core::f64::consts::SQRT_2)
+}
+
+/// Returns 3D Perlin gradient noise.
+pub fn perlin3<T: Float>(x: T, y: T, z: T, seed: u32) -> T {
+    let ((i, fx), (j, fy), (k, fz)) = (split(x), split(y), split(z));
+    let g = |di: i32, dj: i32, dk: i32| {
+        let h = hash(seed, &[i.wrapping_add(di), j.wrapping_add(dj), k.wrapping_add(dk)]);
+        let (dx, dy, dz) = (fx - T::from_i32(di), fy - T::from_i32(dj), fz - T::from_i32(dk));
+        grad3(h, dx, dy, dz)
+    };
+    let (u, w, s) = (fade(fx), fade(fy), fade(fz));
+    let plane = |dk| g(0, 0, dk).lerp(g(1, 0, dk), u).lerp(g(0, 1, dk).lerp(g(1, 1, dk), u), w);
+    // The edge gradients have length `sqrt(2)`, so the unscaled noise peaks at `sqrt(6) / 2`.
+    plane(0).lerp(plane(1), s) * T::from_f64(0.816_496_580_927_726)
+}
+
+/// Returns 2D simplex noise.
+///
+/// Cheaper than `perlin2` and without its axis aligned artifacts.
+pub fn simplex2<T: Float>(x: T, y: T, seed: u32) -> T {
+    let sqrt3 = T::from_f64(3.0).sqrt();
+    let skew = (sqrt3 - T::one()) / T::from_f64(2.0);
+    let unskew = (T::from_f64(3.0) - sqrt3) / T::from_f64(6.0);
+
+    let s = (x + y) * skew;
+    let (i, j) = ((x + s).floor(), (y + s).floor());
+    let t = (i + j) * unskew;
+    let (x0, y0) = (x - (i - t), y - (j - t));
+    let (i1, j1) = if x0 > y0 { (1, 0) } else { (0, 1) };
+    let (i, j) = (i.to_i32(), j.to_i32());
+
+    let corner = |di: i32, dj: i32, dx: T, dy: T| {
+        let t = T::from_f64(0.5) - dx * dx - dy * dy;
+        if t <= T::zero() { return T::zero(); }
+        let h = hash(seed, &[i.wrapping_add(di), j.wrapping_add(dj)]);
+        let (gx, gy) = GRAD2[h as usize % 8];
+        let t2 = t * t;
+        t2 * t2 * (T::from_f64(gx) * dx + T::from_f64(gy) * dy)
+    };
+    let n = corner(0, 0, x0, y0)
+        + corner(i1, j1, x0 - T::from_i32(i1) + unskew, y0 - T::from_i32(j1) + unskew)
+        + corner(1, 1, x0 - T::one() + unskew + unskew, y0 - T::one() + unskew + unskew);
+    // Normalizes the peak of the kernel sum to 1.
+    n * T::from_f64(99.204_334_582_718_7)
+}
+
+/// Returns 3D simplex noise.
+///
+/// Cheaper than `perlin3` and without its axis aligned artifacts.
+pub fn simplex3<T: Float>(x: T, y: T, z: T, seed: u32) -> T {
+    let skew = T::one() / T::from_f64(3.0);
+    let unskew = T::one() / T::from_f64(6.0);
+
+    let s = (x + y + z) * skew;
+    let (i, j, k) = ((x + s).floor(), (y + s).floor(), (z + s).floor());
+    let t = (i + j + k) * unskew;
+    let (x0, y0, z0) = (x - (i - t), y - (j - t), z - (k - t));
+    // The second and third corners of the simplex containing the point.
+    let ((i1, j1, k1), (i2, j2, k2)) = if x0 >= y0 {
+        if y0 >= z0 {
+            ((1, 0, 0), (1, 1, 0))
+        } else if x0 >= z0 {
+            ((1, 0, 0), (1, 0, 1))
+        } else {
+            ((0, 0, 1), (1, 0, 1))
+        }
+    } else if y0 < z0 {
+        ((0, 0, 1), (0, 1, 1))
+    } else if x0 < z0 {
+        ((0, 1, 0), (0, 1, 1))
+    } else {
+        ((0, 1, 0), (1, 1, 0))
+    };
+    let (i, j, k) = (i.to_i32(), j.to_i32(), k.to_i32());
+
+    let corner = |di: i32, dj: i32, dk: i32, n: T| {
+        let offset = unskew * n;
+        let dx = x0 - T::from_i32(di) + offset;
+        let dy = y0 - T::from_i32(dj) + offset;
+        let dz = z0 - T::from_i32(dk) + offset;
+        let t = T::from_f64(0.5) - dx * dx - dy * dy - dz * dz;
+        if t <= T::zero() { return T::zero(); }
+        let h = hash(seed, &[i.wrapping_add(di), j.wrapping_add(dj), k.wrapping_add(dk)]);
+        let t2 = t * t;
+        t2 * t2 * grad3(h, dx, dy, dz)
+    };
+    let n = corner(0, 0, 0, T::zero())
+        + corner(i1, j1, k1, T::one())
+        + corner(i2, j2, k2, T::from_f64(2.0))
+        + corner(1, 1, 1, T::from_f64(3.0));
+    // Normalizes the peak of the kernel sum to 1.
+    n * T::from_f64(76.883_646_832_107_7)
+}
+
+const DIAG: f64 = ::core::f64::consts::FRAC_1_SQRT_2;
+
+/// Unit gradients in eight directions.
+const GRAD2: [(f64, f64); 8] = [
+    (1.0, 0.0), (-1.0, 0.0), (0.0, 1.0), (0.0, -1.0),
+    (DIAG, DIAG),
+    (-DIAG, DIAG),
+    (DIAG, -DIAG),
+    (-DIAG, -DIAG),
+];
+
+/// Returns the dot product with one of the twelve cube edge gradients.
+fn grad3<T: Float>(h: u32, dx: T, dy: T, dz: T) -> T {
+    let (a, b) = match h % 12 / 4 {
+        0 => (dx, dy),
+        1 => (dx, dz),
+        _ => (dy, dz),
+    };
+    let a = if h & 1 == 0 { a } else { -a };
+    let b = if h & 2 == 0 { b } else { -b };
+    a + b
+}
+
+/// Returns the integer lattice cell and the offset within it.
+fn split<T: Float>(x: T) -> (i32, T) {
+    let i = x.floor();
+    (i.to_i32(), x - i)
+}
+
+/// Returns a smooth ramp from 0 to 1 with zero first and second derivatives at the ends.
+fn fade<T: Float>(t: T) -> T {
+    t * t * t * (t * (t * T::from_f64(6.0) - T::from_f64(15.0)) + T::from_f64(10.0))
+}
+
+/// Maps a hash to `[-1, 1]`.
+fn unit<T: Float>(h: u32) -> T {
+    T::from_u32(h >> 8) * T::from_f64(2.0 / 16_777_215.0) - T::one()
+}
+
+/// Hashes lattice coordinates with a seed.
+fn hash(seed: u32, coords: &[i32]) -> u32 {
+    coords.iter().fold(mix(seed), |h, &c| mix(h ^ c as u32))
+}
+
+/// A 32 bit integer finalizer with low bias.
+fn mix(mut h: u32) -> u32 {
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x7feb_352d);
+    h ^= h >> 15;
+    h = h.wrapping_mul(0x846c_a68b);
+    h ^ (h >> 16)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_range() {
+        let mut max = [0.0_f64; 8];
+        for n in 0..20_000 {
+            let x = n as f64 * 0.173 - 1700.0;
+            let (y, z) = (x * 0.618 + 0.3, x * -0.377 + 11.9);
+            let v = [
+                value1(x, 1), value2(x, y, 2), value3(x, y, z, 3), perlin1(x, 4),
+                perlin2(x, y, 5), perlin3(x, y, z, 6), simplex2(x, y, 7), simplex3(x, y, z, 8),
+            ];
+            for (m, v) in max.iter_mut().zip(v.iter()) {
+                assert!(v.abs() <= 1.0, "{}", v);
+                *m = m.max(v.abs());
+            }
+        }
+        for m in &max { assert!(*m > 0.5, "{}", m); }
+    }
+
+    #[test]
+    fn test_lattice() {
+        for &(x, y, z) in &[(0.0_f32, 0.0, 0.0), (3.0, -7.0, 12.0), (-1.0, 5.0, -2.0)] {
+            assert_eq!(perlin1(x, 9), 0.0);
+            assert_eq!(perlin2(x, y, 9), 0.0);
+            assert_eq!(perlin3(x, y, z, 9), 0.0);
+            assert_eq!(value2(x, y, 9), unit(hash(9, &[x as i32, y as i32])));
+        }
+        assert_eq!(simplex2(0.0_f64, 0.0, 1), 0.0);
+        assert_eq!(simplex3(0.0_f64, 0.0, 0.0, 1), 0.0);
+    }
+
+    #[test]
+    fn test_continuity() {
+        let h = 1e-7;
+        for n in 0..1000 {
+            let x = n as f64 * 0.0137 - 3.0;
+            let y = 1.0 - x * 0.5;
+            assert!((value3(x, y, x, 0) - value3(x + h, y, x, 0)).abs() < 1e-5);
+            assert!((perlin2(x, y, 0) - perlin2(x + h, y, 0)).abs() < 1e-5);
+            assert!((simplex2(x, y, 0) - simplex2(x + h, y + h, 0)).abs() < 1e-5);
+            assert!((simplex3(x, y, -x, 0) - simplex3(x, y + h, -x, 0)).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_seed() {
+        assert_eq!(simplex3(0.3_f64, 0.6, 0.9, 42), simplex3(0.3, 0.6, 0.9, 42));
+        assert!(simplex3(0.3_f64, 0.6, 0.9, 42) != simplex3(0.3, 0.6, 0.9, 43));
+        assert!((perlin2(0.5_f32, 0.5, 1) - perlin2(0.5_f64, 0.5, 1) as f32).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,184 @@
+//! Totally ordered float wrappers.
+//!
+//! Both wrappers implement `Ord`, `Eq` and `Hash`, so they can be used as keys
+//! in `BTreeMap`, `HashMap` or `BinaryHeap`, and they implement every trait
+//! required by `Float`, so generic float code accepts them unchanged.
+
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use {Float, ParseFloatError};
+
+/// A float that is guaranteed not to be NaN.
+///
+/// Any operation that would produce NaN panics instead,
+/// including `Consts::nan()`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NotNan<T>(T);
+
+impl<T: Float> NotNan<T> {
+    /// Returns the wrapped value, or `None` if it is NaN.
+    #[inline(always)]
+    pub fn new(value: T) -> Option<NotNan<T>> {
+        if value.is_nan() { None } else { Some(NotNan(value)) }
+    }
+
+    /// Returns the inner value.
+    #[inline(always)]
+    pub fn into_inner(self) -> T { self.0 }
+
+    #[inline(always)]
+    fn wrap(value: T) -> NotNan<T> {
+        assert!(!value.is_nan(), "NaN result in NotNan operation");
+        NotNan(value)
+    }
+}
+
+/// A float with a total ordering.
+///
+/// All NaNs are equal to each other and greater than every other value,
+/// while `-0.0` and `0.0` are equal.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Ordered<T>(pub T);
+
+impl<T: Float> Ordered<T> {
+    /// Returns the inner value.
+    #[inline(always)]
+    pub fn into_inner(self) -> T { self.0 }
+
+    #[inline(always)]
+    fn wrap(value: T) -> Ordered<T> { Ordered(value) }
+}
+
+/// Hashes a float consistently with the wrapper equality:
+/// zeros and NaNs are hashed by a canonical bit pattern.
+#[inline(always)]
+fn hash_float<T: Float, H: Hasher>(value: T, state: &mut H) {
+    let bits = if value.is_nan() {
+        0x7ff8_0000_0000_0000
+    } else if value == T::zero() {
+        0
+    } else {
+        value.to_f64().to_bits()
+    };
+    bits.hash(state)
+}
+
+impl<T: Float> PartialEq for NotNan<T> {
+    #[inline(always)]
+    fn eq(&self, other: &NotNan<T>) -> bool { self.0 == other.0 }
+}
+
+impl<T: Float> Eq for NotNan<T> {}
+
+impl<T: Float> PartialOrd for NotNan<T> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &NotNan<T>) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl<T: Float> Ord for NotNan<T> {
+    #[inline(always)]
+    fn cmp(&self, other: &NotNan<T>) -> Ordering {
+        self.0.partial_cmp(&other.0).expect("NotNan contains NaN")
+    }
+}
+
+impl<T: Float> Hash for NotNan<T> {
+    #[inline(always)]
+    fn hash<H: Hasher>(&self, state: &mut H) { hash_float(self.0, state) }
+}
+
+impl<T: Float> PartialEq for Ordered<T> {
+    #[inline(always)]
+    fn eq(&self, other: &Ordered<T>) -> bool { self.cmp(other) == Ordering::Equal }
+}
+
+impl<T: Float> Eq for Ordered<T> {}
+
+impl<T: Float> PartialOrd for Ordered<T> {
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Ordered<T>) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl<T: Float> Ord for Ordered<T> {
+    #[inline(always)]
+    fn cmp(&self, other: &Ordered<T>) -> Ordering {
+        match self.0.partial_cmp(&other.0) {
+            Some(ordering) => ordering,
+            None => match (self.0.is_nan(), other.0.is_nan()) {
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Greater,
+                _ => Ordering::Less,
+            },
+        }
+    }
+}
+
+impl<T: Float> Hash for Ordered<T> {
+    #[inline(always)]
+    fn hash<H: Hasher>(&self, state: &mut H) { hash_float(self.0, state) }
+}
+
+// Parsing "nan" is an error rather than a panic.
+float_impl!(@impl [T: Float] NotNan<T>, T, NotNan::wrap, |v| NotNan::new(v).ok_or(ParseFloatError::new()));
+float_impl!(@impl [T: Float] Ordered<T>, T, Ordered::wrap);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use ParseFloat;
+
+    fn hypot_generic<T: Float>(a: T, b: T) -> T { (a * a + b * b).sqrt() }
+
+    #[test]
+    fn test_not_nan() {
+        assert!(NotNan::new(f64::NAN).is_none());
+        let a = NotNan::new(3.0_f64).unwrap();
+        let b = NotNan::new(4.0_f64).unwrap();
+        assert_eq!(hypot_generic(a, b).into_inner(), 5.0);
+        assert_eq!(a.cmp(&b), Ordering::Less);
+        let mut v = [b, a, NotNan::new(-1.0).unwrap()];
+        v.sort_unstable();
+        assert_eq!(v[0].into_inner(), -1.0);
+        assert_eq!(v[2], b);
+        assert_eq!(<NotNan<f64> as ParseFloat>::from_str("2.5"), Ok(NotNan::new(2.5).unwrap()));
+        assert!(<NotNan<f64> as ParseFloat>::from_str("nan").is_err());
+        assert!(<NotNan<f32> as ParseFloat>::from_str("-NaN").is_err());
+        assert_eq!(<Ordered<f32> as ParseFloat>::from_str("nan").map(|x| x.0.is_nan()), Ok(true));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_not_nan_panics() {
+        let zero = NotNan::new(0.0_f32).unwrap();
+        let _ = zero / zero;
+    }
+
+    #[test]
+    fn test_ordered() {
+        let mut v = [Ordered(f32::NAN), Ordered(1.0), Ordered(-f32::INFINITY), Ordered(-0.0)];
+        v.sort_unstable();
+        assert_eq!(v[0].0, -f32::INFINITY);
+        assert_eq!(v[1], Ordered(0.0));
+        assert_eq!(v[2].0, 1.0);
+        assert!(v[3].0.is_nan());
+        assert_eq!(Ordered(f64::NAN), Ordered(-f64::NAN));
+        assert_eq!(hypot_generic(Ordered(3.0_f32), Ordered(4.0)), Ordered(5.0));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_hash() {
+        use std::collections::HashSet;
+
+        let mut set = HashSet::new();
+        set.insert(Ordered(0.0_f64));
+        set.insert(Ordered(-0.0));
+        set.insert(Ordered(f64::NAN));
+        set.insert(Ordered(-f64::NAN));
+        assert_eq!(set.len(), 2);
+
+        let mut set = HashSet::new();
+        set.insert(NotNan::new(0.0_f32).unwrap());
+        assert!(!set.insert(NotNan::new(-0.0).unwrap()));
+    }
+}
@@ -0,0 +1,254 @@
+//! Robust geometric predicates.
+//!
+//! These are the adaptive predicates of Shewchuk, "Adaptive Precision Floating-Point Arithmetic
+//! and Fast Robust Geometric Predicates". Each first evaluates the determinant in plain arithmetic
+//! with a bound on its rounding error, and only when the sign is in doubt does it recompute the
+//! determinant exactly, as a sum of non-overlapping floats.
+//! The sign is then correct unless an intermediate product overflows or underflows.
+//! Points are `[x, y]` arrays.
+
+use {Bits, Float};
+
+/// Returns half the distance from 1 to the next float, the relative rounding error.
+fn epsilon<T: Float + Bits>() -> T { T::one().ulp() / T::from_f64(2.0) }
+
+fn two_sum<T: Float>(a: T, b: T) -> (T, T) {
+    let x = a + b;
+    let b_virtual = x - a;
+    let a_virtual = x - b_virtual;
+    (x, (a - a_virtual) + (b - b_virtual))
+}
+
+fn fast_two_sum<T: Float>(a: T, b: T) -> (T, T) {
+    let x = a + b;
+    (x, b - (x - a))
+}
+
+fn two_product<T: Float>(a: T, b: T) -> (T, T) {
+    let x = a * b;
+    (x, a.mul_add(b, -x))
+}
+
+/// Writes `a - b` as an expansion and returns its length.
+fn diff<T: Float>(a: T, b: T, h: &mut [T]) -> usize {
+    let (x, y) = two_sum(a, -b);
+    if y == T::zero() {
+        h[0] = x;
+        1
+    } else {
+        h[0] = y;
+        h[1] = x;
+        2
+    }
+}
+
+/// Writes the sum of two expansions into `h` and returns its length.
+///
+/// Expansions are ordered by increasing magnitude, without zeros except for zero itself.
+fn sum<T: Float>(e: &[T], f: &[T], h: &mut [T]) -> usize {
+    let (mut i, mut j, mut len) = (0, 0, 0);
+    let mut next = || {
+        if j == f.len() || (i < e.len() && e[i].abs() < f[j].abs()) {
+            i += 1;
+            e[i - 1]
+        } else {
+            j += 1;
+            f[j - 1]
+        }
+    };
+    let mut q = next();
+    for _ in 1..e.len() + f.len() {
+        let (x, y) = two_sum(q, next());
+        if y != T::zero() {
+            h[len] = y;
+            len += 1;
+        }
+        q = x;
+    }
+    if q != T::zero() || len == 0 {
+        h[len] = q;
+        len += 1;
+    }
+    len
+}
+
+/// Writes the product of an expansion and a float into `h` and returns its length.
+fn scale<T: Float>(e: &[T], b: T, h: &mut [T]) -> usize {
+    let mut len = 0;
+    let mut push = |h: &mut [T], x: T| if x != T::zero() {
+        h[len] = x;
+        len += 1;
+    };
+    let (mut q, low) = two_product(e[0], b);
+    push(h, low);
+    for &x in &e[1..] {
+        let (high, low) = two_product(x, b);
+        let (s, y) = two_sum(q, low);
+        push(h, y);
+        let (s, y) = fast_two_sum(high, s);
+        push(h, y);
+        q = s;
+    }
+    if q != T::zero() || len == 0 {
+        h[len] = q;
+        len += 1;
+    }
+    len
+}
+
+/// Writes the product of two expansions into `h` and returns its length.
+///
+/// Supports up to 512 terms in the product.
+fn product<T: Float>(e: &[T], f: &[T], h: &mut [T]) -> usize {
+    let mut scaled = [T::zero(); 32];
+    let mut acc = [T::zero(); 512];
+    let mut len = scale(e, f[0], h);
+    for &b in &f[1..] {
+        let n = scale(e, b, &mut scaled);
+        let m = sum(&h[..len], &scaled[..n], &mut acc);
+        h[..m].copy_from_slice(&acc[..m]);
+        len = m;
+    }
+    len
+}
+
+/// Writes `a * b - c * d` for expansions of up to two terms, returning the length of up to 16.
+fn cross<T: Float>(a: &[T], b: &[T], c: &[T], d: &[T], h: &mut [T]) -> usize {
+    let (mut ab, mut cd) = ([T::zero(); 8], [T::zero(); 8]);
+    let n = product(a, b, &mut ab);
+    let m = product(c, d, &mut cd);
+    for x in &mut cd[..m] { *x = -*x; }
+    sum(&ab[..n], &cd[..m], h)
+}
+
+/// Returns a positive value if `a`, `b` and `c` are in counterclockwise order,
+/// a negative value if they are clockwise and zero if they are collinear.
+///
+/// The result approximates twice the signed area of the triangle.
+pub fn orient2d<T: Float + Bits>(a: [T; 2], b: [T; 2], c: [T; 2]) -> T {
+    let left = (a[0] - c[0]) * (b[1] - c[1]);
+    let right = (a[1] - c[1]) * (b[0] - c[0]);
+    let det = left - right;
+    // The determinant is exact when the terms have opposite signs or either is zero.
+    if (left > T::zero()) != (right > T::zero()) || left == T::zero() || right == T::zero() {
+        return det;
+    }
+    let eps = epsilon::<T>();
+    let bound = (T::from_f64(3.0) + T::from_f64(16.0) * eps) * eps * (left.abs() + right.abs());
+    if det.abs() > bound { return det; }
+    orient2d_exact(a, b, c)
+}
+
+fn orient2d_exact<T: Float>(a: [T; 2], b: [T; 2], c: [T; 2]) -> T {
+    let mut d = [[T::zero(); 2]; 4];
+    let acx = diff(a[0], c[0], &mut d[0]);
+    let bcy = diff(b[1], c[1], &mut d[1]);
+    let acy = diff(a[1], c[1], &mut d[2]);
+    let bcx = diff(b[0], c[0], &mut d[3]);
+    let mut det = [T::zero(); 16];
+    let n = cross(&d[0][..acx], &d[1][..bcy], &d[2][..acy], &d[3][..bcx], &mut det);
+    det[n - 1]
+}
+
+/// Returns a positive value if `d` lies inside the circle through `a`, `b` and `c`,
+/// a negative value if it lies outside and zero if the four points are cocircular.
+///
+/// The points `a`, `b` and `c` must be in counterclockwise order, or the sign is reversed.
+pub fn incircle<T: Float + Bits>(a: [T; 2], b: [T; 2], c: [T; 2], d: [T; 2]) -> T {
+    let (adx, ady) = (a[0] - d[0], a[1] - d[1]);
+    let (bdx, bdy) = (b[0] - d[0], b[1] - d[1]);
+    let (cdx, cdy) = (c[0] - d[0], c[1] - d[1]);
+    let (bdxcdy, cdxbdy) = (bdx * cdy, cdx * bdy);
+    let (cdxady, adxcdy) = (cdx * ady, adx * cdy);
+    let (adxbdy, bdxady) = (adx * bdy, bdx * ady);
+    let alift = adx * adx + ady * ady;
+    let blift = bdx * bdx + bdy * bdy;
+    let clift = cdx * cdx + cdy * cdy;
+    let det = alift * (bdxcdy - cdxbdy) + blift * (cdxady - adxcdy) + clift * (adxbdy - bdxady);
+    let permanent = (bdxcdy.abs() + cdxbdy.abs()) * alift
+        + (cdxady.abs() + adxcdy.abs()) * blift
+        + (adxbdy.abs() + bdxady.abs()) * clift;
+    let eps = epsilon::<T>();
+    let bound = (T::from_f64(10.0) + T::from_f64(96.0) * eps) * eps * permanent;
+    if det.abs() > bound { return det; }
+    incircle_exact(a, b, c, d)
+}
+
+fn incircle_exact<T: Float>(a: [T; 2], b: [T; 2], c: [T; 2], d: [T; 2]) -> T {
+    // Differences to `d`, as expansions of up to two terms each.
+    let mut e = [[T::zero(); 2]; 6];
+    let n = [
+        diff(a[0], d[0], &mut e[0]),
+        diff(a[1], d[1], &mut e[1]),
+        diff(b[0], d[0], &mut e[2]),
+        diff(b[1], d[1], &mut e[3]),
+        diff(c[0], d[0], &mut e[4]),
+        diff(c[1], d[1], &mut e[5]),
+    ];
+    let x = |i: usize| &e[2 * i][..n[2 * i]];
+    let y = |i: usize| &e[2 * i + 1][..n[2 * i + 1]];
+
+    let mut det = [T::zero(); 1536];
+    let mut len = 0;
+    for i in 0..3 {
+        let (j, k) = ((i + 1) % 3, (i + 2) % 3);
+        let (mut lift, mut minor, mut term, mut total) =
+            ([T::zero(); 16], [T::zero(); 16], [T::zero(); 512], [T::zero(); 1536]);
+        // `x^2 + y^2`, as `x * x - y * (-y)`.
+        let mut neg_y = [T::zero(); 2];
+        for (ny, &v) in neg_y.iter_mut().zip(y(i)) { *ny = -v; }
+        let lift_len = cross(x(i), x(i), y(i), &neg_y[..y(i).len()], &mut lift);
+        let minor_len = cross(x(j), y(k), x(k), y(j), &mut minor);
+        let term_len = product(&lift[..lift_len], &minor[..minor_len], &mut term);
+        let m = sum(&det[..len], &term[..term_len], &mut total);
+        det[..m].copy_from_slice(&total[..m]);
+        len = m;
+    }
+    det[len - 1]
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sign(x: f64) -> i32 { if x > 0.0 { 1 } else if x < 0.0 { -1 } else { 0 } }
+
+    #[test]
+    fn test_orient2d() {
+        assert!(orient2d([0.0_f64, 0.0], [1.0, 0.0], [0.0, 1.0]) > 0.0);
+        assert!(orient2d([0.0_f32, 0.0], [0.0, 1.0], [1.0, 0.0]) < 0.0);
+        assert_eq!(orient2d([1.0_f64, 1.0], [2.0, 2.0], [3.0, 3.0]), 0.0);
+        // Points within a few ulps of the line `y = x`, where the plain determinant
+        // gets the sign wrong. The exact orientation is the sign of `py - px`.
+        let ulp = 0.5_f64.ulp();
+        for i in 0..32_i32 {
+            for j in 0..32 {
+                let p = [0.5 + i as f64 * ulp, 0.5 + j as f64 * ulp];
+                assert_eq!(sign(orient2d(p, [12.0, 12.0], [24.0, 24.0])), (j - i).signum(), "{} {}", i, j);
+            }
+        }
+    }
+
+    #[test]
+    fn test_incircle() {
+        let (a, b, c) = ([1.0_f64, 0.0], [0.0, 1.0], [-1.0, 0.0]);
+        assert!(incircle(a, b, c, [0.0, 0.0]) > 0.0);
+        assert!(incircle(a, b, c, [2.0, 2.0]) < 0.0);
+        assert!(incircle(c, b, a, [0.0, 0.0]) < 0.0);
+        assert_eq!(incircle(a, b, c, [0.0, -1.0]), 0.0);
+        // Within a few ulps of the unit circle, the exact sign is that of `1 - x^2 - y^2`.
+        let ulp = 1.0_f64.ulp();
+        for i in 0..8 {
+            for j in -8..8 {
+                let d = [i as f64 * ulp, -1.0 + j as f64 * ulp];
+                let expected = if j > 0 { 1 } else if j < 0 || i > 0 { -1 } else { 0 };
+                assert_eq!(sign(incircle(a, b, c, d)), expected, "{} {}", i, j);
+            }
+        }
+        // Cocircular after translation, where the differences to `d` are inexact.
+        let t = [1e10_f64 + 0.5, -3e9];
+        let shift = |p: [f64; 2]| [p[0] + t[0], p[1] + t[1]];
+        assert_eq!(incircle(shift([3.0, 0.0]), shift([0.0, 3.0]), shift([-3.0, 0.0]), shift([0.0, -3.0])), 0.0);
+        assert!(incircle([0.0_f32, 0.0], [1.0, 0.0], [0.0, 1.0], [0.25, 0.25]) > 0.0);
+    }
+}
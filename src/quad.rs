@@ -0,0 +1,401 @@
+//! Impls for the quadruple precision `f128`.
+//!
+//! Requires a nightly compiler, enabled by the `f128` feature.
+//! The math goes through the standard library's own `f128` routines,
+//! so the `deterministic` feature does not apply to them.
+
+use core::cmp::Ordering;
+use core::num::FpCategory;
+
+use {
+    Abs, ApproxEq, Cast, Cbrt, Classify, Clamp, ConstOne, ConstRadians, ConstZero, Consts, Exp,
+    FromPrimitive, Log, Max, Midpoint, Min, MulAdd, One, Powf, Powi, Radians, Recip, Round, Signum,
+    Sqrt, TotalOrder, Trig, Zero,
+};
+
+impl Min for f128 {
+    #[inline(always)]
+    fn min(self, other: f128) -> f128 { f128::min(self, other) }
+}
+
+impl Max for f128 {
+    #[inline(always)]
+    fn max(self, other: f128) -> f128 { f128::max(self, other) }
+}
+
+impl Midpoint for f128 {
+    #[inline(always)]
+    fn midpoint(self, other: f128) -> f128 { f128::midpoint(self, other) }
+}
+
+impl Clamp for f128 {
+    #[inline(always)]
+    fn clamp(self, min: f128, max: f128) -> f128 { f128::clamp(self, min, max) }
+
+    #[inline(always)]
+    fn clamp01(self) -> f128 { f128::clamp(self, 0.0, 1.0) }
+}
+
+impl Signum for f128 {
+    #[inline(always)]
+    fn signum(self) -> f128 { f128::signum(self) }
+}
+
+impl Abs for f128 {
+    #[inline(always)]
+    fn abs(self) -> f128 { f128::abs(self) }
+
+    #[inline(always)]
+    fn copysign(self, sign: f128) -> f128 { f128::copysign(self, sign) }
+
+    #[inline(always)]
+    fn hypot(self, other: f128) -> f128 { f128::hypot(self, other) }
+}
+
+impl Powf for f128 {
+    #[inline(always)]
+    fn powf(self, other: f128) -> f128 { f128::powf(self, other) }
+}
+
+impl Powi for f128 {
+    #[inline(always)]
+    fn powi(self, n: i32) -> f128 { f128::powi(self, n) }
+
+    #[inline(always)]
+    fn sqr(self) -> f128 { self * self }
+
+    #[inline(always)]
+    fn cube(self) -> f128 { self * self * self }
+}
+
+impl MulAdd for f128 {
+    #[inline(always)]
+    fn mul_add(self, a: f128, b: f128) -> f128 { f128::mul_add(self, a, b) }
+}
+
+impl Exp for f128 {
+    #[inline(always)]
+    fn exp(self) -> f128 { f128::exp(self) }
+
+    #[inline(always)]
+    fn exp2(self) -> f128 { f128::exp2(self) }
+
+    #[inline(always)]
+    fn exp_m1(self) -> f128 { f128::exp_m1(self) }
+}
+
+impl Log for f128 {
+    #[inline(always)]
+    fn ln(self) -> f128 { f128::ln(self) }
+
+    #[inline(always)]
+    fn log(self, base: f128) -> f128 { f128::ln(self) / f128::ln(base) }
+
+    #[inline(always)]
+    fn log2(self) -> f128 { f128::log2(self) }
+
+    #[inline(always)]
+    fn log10(self) -> f128 { f128::log10(self) }
+
+    #[inline(always)]
+    fn ln_1p(self) -> f128 { f128::ln_1p(self) }
+}
+
+impl Round for f128 {
+    #[inline(always)]
+    fn floor(self) -> f128 { f128::floor(self) }
+
+    #[inline(always)]
+    fn ceil(self) -> f128 { f128::ceil(self) }
+
+    #[inline(always)]
+    fn round(self) -> f128 { f128::round(self) }
+
+    #[inline(always)]
+    fn trunc(self) -> f128 { f128::trunc(self) }
+
+    #[inline(always)]
+    fn fract(self) -> f128 { f128::fract(self) }
+}
+
+impl Classify for f128 {
+    #[inline(always)]
+    fn is_nan(self) -> bool { f128::is_nan(self) }
+
+    #[inline(always)]
+    fn is_infinite(self) -> bool { f128::is_infinite(self) }
+
+    #[inline(always)]
+    fn is_finite(self) -> bool { f128::is_finite(self) }
+
+    #[inline(always)]
+    fn is_normal(self) -> bool { f128::is_normal(self) }
+
+    #[inline(always)]
+    fn classify(self) -> FpCategory { f128::classify(self) }
+}
+
+impl ApproxEq for f128 {
+    #[inline(always)]
+    fn approx_eq(self, other: f128, epsilon: f128) -> bool {
+        self == other || (self - other).abs() <= epsilon
+    }
+
+    #[inline(always)]
+    fn ulps_eq(self, other: f128, max_ulps: u32) -> bool {
+        if self == other { return true; }
+        if self.is_nan() || other.is_nan() || self.is_sign_negative() != other.is_sign_negative() {
+            return false;
+        }
+        let (a, b) = (self.to_bits(), other.to_bits());
+        a.abs_diff(b) <= max_ulps as u128
+    }
+}
+
+impl TotalOrder for f128 {
+    #[inline(always)]
+    fn total_cmp(self, other: f128) -> Ordering { f128::total_cmp(&self, &other) }
+}
+
+impl ConstRadians for f128 {
+    const QUARTER_TURN: f128 = ::core::f128::consts::FRAC_PI_2;
+    const HALF_TURN: f128 = ::core::f128::consts::PI;
+    const FULL_TURN: f128 = ::core::f128::consts::TAU;
+}
+
+impl Radians for f128 {
+    #[inline(always)]
+    fn _90() -> f128 {
+        ::core::f128::consts::FRAC_PI_2
+    }
+
+    #[inline(always)]
+    fn _180() -> f128 {
+        ::core::f128::consts::PI
+    }
+
+    #[inline(always)]
+    fn _360() -> f128 {
+        <Self as Radians>::_180() * 2.0
+    }
+
+    #[inline(always)]
+    fn deg_to_rad(self) -> Self {
+        self * (::core::f128::consts::PI / 180.0_f128)
+    }
+
+    #[inline(always)]
+    fn rad_to_deg(self) -> Self {
+        self * (180.0_f128 / ::core::f128::consts::PI)
+    }
+}
+
+impl Consts for f128 {
+    #[inline(always)]
+    fn pi() -> f128 { ::core::f128::consts::PI }
+
+    #[inline(always)]
+    fn tau() -> f128 { ::core::f128::consts::TAU }
+
+    #[inline(always)]
+    fn e() -> f128 { ::core::f128::consts::E }
+
+    #[inline(always)]
+    fn epsilon() -> f128 { f128::EPSILON }
+
+    #[inline(always)]
+    fn min_value() -> f128 { f128::MIN }
+
+    #[inline(always)]
+    fn max_value() -> f128 { f128::MAX }
+
+    #[inline(always)]
+    fn min_positive() -> f128 { f128::MIN_POSITIVE }
+
+    #[inline(always)]
+    fn infinity() -> f128 { f128::INFINITY }
+
+    #[inline(always)]
+    fn neg_infinity() -> f128 { f128::NEG_INFINITY }
+
+    #[inline(always)]
+    fn nan() -> f128 { f128::NAN }
+}
+
+impl ConstOne for f128 {
+    const ONE: f128 = 1.0;
+}
+
+impl One for f128 {
+    #[inline(always)]
+    fn one() -> f128 { 1.0 }
+}
+
+impl ConstZero for f128 {
+    const ZERO: f128 = 0.0;
+}
+
+impl Zero for f128 {
+    #[inline(always)]
+    fn zero() -> f128 { 0.0 }
+}
+
+impl Sqrt for f128 {
+    #[inline(always)]
+    fn sqrt(self) -> f128 { f128::sqrt(self) }
+}
+
+impl Recip for f128 {
+    #[inline(always)]
+    fn recip(self) -> f128 { 1.0 / self }
+
+    #[inline(always)]
+    fn rsqrt(self) -> f128 { 1.0 / f128::sqrt(self) }
+}
+
+impl Cbrt for f128 {
+    #[inline(always)]
+    fn cbrt(self) -> f128 { f128::cbrt(self) }
+}
+
+impl Trig for f128 {
+    #[inline(always)]
+    fn sin(self) -> f128 { f128::sin(self) }
+
+    #[inline(always)]
+    fn cos(self) -> f128 { f128::cos(self) }
+
+    #[inline(always)]
+    fn tan(self) -> f128 { f128::tan(self) }
+
+    #[inline(always)]
+    fn asin(self) -> f128 { f128::asin(self) }
+
+    #[inline(always)]
+    fn acos(self) -> f128 { f128::acos(self) }
+
+    #[inline(always)]
+    fn atan(self) -> f128 { f128::atan(self) }
+
+    #[inline(always)]
+    fn atan2(self, other: f128) -> f128 { f128::atan2(self, other) }
+
+    #[inline(always)]
+    fn sinh(self) -> f128 { f128::sinh(self) }
+
+    #[inline(always)]
+    fn cosh(self) -> f128 { f128::cosh(self) }
+
+    #[inline(always)]
+    fn tanh(self) -> f128 { f128::tanh(self) }
+
+    #[inline(always)]
+    fn asinh(self) -> f128 { f128::asinh(self) }
+
+    #[inline(always)]
+    fn acosh(self) -> f128 { f128::acosh(self) }
+
+    #[inline(always)]
+    fn atanh(self) -> f128 { f128::atanh(self) }
+
+    #[inline(always)]
+    fn sin_cos(self) -> (f128, f128) { f128::sin_cos(self) }
+}
+
+impl Cast<f128> for f32 {
+    #[inline(always)]
+    fn cast(self) -> f128 { self as f128 }
+}
+
+impl Cast<f128> for f64 {
+    #[inline(always)]
+    fn cast(self) -> f128 { self as f128 }
+}
+
+impl Cast<f32> for f128 {
+    #[inline(always)]
+    fn cast(self) -> f32 { self as f32 }
+}
+
+impl Cast<f64> for f128 {
+    #[inline(always)]
+    fn cast(self) -> f64 { self as f64 }
+}
+
+impl Cast<f128> for f128 {
+    #[inline(always)]
+    fn cast(self) -> f128 { self }
+}
+
+impl FromPrimitive for f128 {
+    #[inline(always)]
+    fn from_f64(t: f64) -> Self { t as f128 }
+    #[inline(always)]
+    fn from_f32(t: f32) -> Self { t as f128 }
+    #[inline(always)]
+    fn from_isize(t: isize) -> Self { t as f128 }
+    #[inline(always)]
+    fn from_u32(t: u32) -> Self { t as f128 }
+    #[inline(always)]
+    fn from_i32(t: i32) -> Self { t as f128 }
+    #[inline(always)]
+    fn from_u64(t: u64) -> Self { t as f128 }
+    #[inline(always)]
+    fn from_i64(t: i64) -> Self { t as f128 }
+    #[inline(always)]
+    fn from_usize(t: usize) -> Self { t as f128 }
+    #[inline(always)]
+    fn from_u8(t: u8) -> Self { t as f128 }
+    #[inline(always)]
+    fn from_i8(t: i8) -> Self { t as f128 }
+    #[inline(always)]
+    fn from_u16(t: u16) -> Self { t as f128 }
+    #[inline(always)]
+    fn from_i16(t: i16) -> Self { t as f128 }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use {Bits, Float, FormatFloat, ParseFloat, Scalb, ToPrimitive};
+
+    fn length<T: Float>(a: T, b: T) -> T { (a * a + b * b).sqrt() }
+
+    #[test]
+    fn test_float() {
+        assert_eq!(length(3.0_f128, 4.0), 5.0);
+        // More precise than `f64` can represent.
+        let third = 1.0_f128 / 3.0;
+        assert!((third - (1.0_f64 / 3.0) as f128).abs() > 1e-18);
+        assert!(ApproxEq::approx_eq(Trig::sin(<f128 as Consts>::pi() / 6.0), 0.5, 1e-33));
+        assert_eq!(<f128 as FromPrimitive>::from_i32(-7).to_i32(), -7);
+        assert_eq!(Cast::<f64>::cast(0.5_f128), 0.5_f64);
+        assert_eq!(Bits::next_up(1.0_f128) - 1.0, f128::EPSILON);
+        assert_eq!(Scalb::frexp(f128::from_bits(1)), (0.5, -16493));
+        assert_eq!(Scalb::ldexp(1.0_f128, -16494), f128::from_bits(1));
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(<f128 as ParseFloat>::from_str("0.1"), Ok(1.0 / 10.0));
+        assert_eq!(<f128 as ParseFloat>::from_str("-1.5e3"), Ok(-1500.0));
+        assert_eq!(<f128 as ParseFloat>::from_str("1e5000"), Ok(f128::INFINITY));
+        assert_eq!(<f128 as ParseFloat>::from_str("0x1.0000000000000000000000000001p0"),
+                   Ok(1.0 + f128::EPSILON));
+        assert_eq!(<f128 as ParseFloat>::from_str("0x1p-16494"), Ok(f128::from_bits(1)));
+        assert!(<f128 as ParseFloat>::from_str("NaN").unwrap().is_nan());
+        for s in &["", ".", "1e", "1.2.3", "0x1g", "--1"] {
+            assert!(<f128 as ParseFloat>::from_str(s).is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(<f128 as Consts>::pi().to_string_with_precision(30), "3.141592653589793238462643383280");
+        assert_eq!((-2.5_f128).to_string_with_precision(0), "-2");
+        assert_eq!((1.0_f128 / 3.0).to_string_with_precision(36), "0.333333333333333333333333333333333300");
+        assert_eq!(1e-40_f128.to_string_with_precision(3), "0.000");
+        assert_eq!(1e36_f128.to_string_with_precision(1), "1000000000000000000000000000000000000.0");
+        assert_eq!(f128::NEG_INFINITY.to_string_with_precision(2), "-inf");
+    }
+}
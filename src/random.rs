@@ -0,0 +1,82 @@
+//! Random sampling from a source of uniform bits.
+//!
+//! The source is any `FnMut() -> u64` returning uniformly distributed integers,
+//! such as `|| rng.next_u64()` for a generator of the `rand` crate.
+
+use Float;
+
+/// Random values for any `Float`.
+pub trait RandomFloat: Float {
+    /// Returns a uniformly distributed value in `[0, 1)`.
+    ///
+    /// Built from as many of the top bits of one `u64` as the mantissa holds, up to all 64,
+    /// so every value is a multiple of the same power of two and exactly representable.
+    fn random_unit<R: FnMut() -> u64>(next: &mut R) -> Self {
+        let digits = (Self::one() - Self::epsilon().log2()).round().to_usize().min(64);
+        let scale = Self::from_f64(0.5).powi(digits as i32);
+        Self::from_u64(next() >> (64 - digits)) * scale
+    }
+
+    /// Returns a uniformly distributed value between `min` and `max`.
+    ///
+    /// The value is below `max` unless rounding reaches it.
+    fn random_range<R: FnMut() -> u64>(next: &mut R, min: Self, max: Self) -> Self {
+        min + (max - min) * Self::random_unit(next)
+    }
+
+    /// Returns one or minus one with equal probability.
+    fn random_sign<R: FnMut() -> u64>(next: &mut R) -> Self {
+        if next() >> 63 == 0 { Self::one() } else { -Self::one() }
+    }
+}
+
+impl<T: Float> RandomFloat for T {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// SplitMix64, a small generator with good statistical quality.
+    fn splitmix(seed: u64) -> impl FnMut() -> u64 {
+        let mut state = seed;
+        move || {
+            state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            z ^ (z >> 31)
+        }
+    }
+
+    #[test]
+    fn test_random_unit() {
+        assert_eq!(f64::random_unit(&mut || 0), 0.0);
+        // All ones is the largest value below one, not one after rounding.
+        assert_eq!(f64::random_unit(&mut || u64::MAX), 1.0 - f64::EPSILON / 2.0);
+        assert_eq!(f32::random_unit(&mut || u64::MAX), 1.0 - f32::EPSILON / 2.0);
+        assert_eq!(f32::random_unit(&mut || 1 << 63), 0.5);
+        let mut next = splitmix(7);
+        let mut sum = 0.0;
+        for _ in 0..4096 {
+            let x = f64::random_unit(&mut next);
+            assert!((0.0..1.0).contains(&x));
+            sum += x;
+        }
+        assert!((sum / 4096.0 - 0.5).abs() < 0.02);
+    }
+
+    #[test]
+    fn test_random_range_and_sign() {
+        let mut next = splitmix(42);
+        let mut signs = 0.0;
+        for _ in 0..4096 {
+            let x = f32::random_range(&mut next, -3.0, 5.0);
+            assert!((-3.0..=5.0).contains(&x));
+            let s = f64::random_sign(&mut next);
+            assert!(s == 1.0 || s == -1.0);
+            signs += s;
+        }
+        assert!(signs.abs() < 256.0);
+        assert_eq!(f64::random_range(&mut || 1 << 62, 2.0, 4.0), 2.5);
+    }
+}
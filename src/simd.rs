@@ -0,0 +1,134 @@
+//! Lane-wise impls for portable SIMD vectors.
+//!
+//! Requires a nightly compiler, enabled by the `simd` feature.
+//! Operations without a native vector instruction are evaluated one lane at a time
+//! using the scalar impls, which the optimizer may still vectorize.
+
+use core::array;
+use core::simd::num::SimdFloat;
+use core::simd::{f32x4, f32x8, f64x2, f64x4};
+
+use {ConstOne, ConstZero, Max, Min, One, Powf, Signum, Sqrt, Trig, Zero};
+
+macro_rules! simd_impls {
+    ($($t:ident: $s:ident;)*) => {
+        $(
+            impl ConstZero for $t {
+                const ZERO: $t = $t::splat(0.0);
+            }
+
+            impl Zero for $t {
+                #[inline(always)]
+                fn zero() -> $t { $t::splat(0.0) }
+            }
+
+            impl ConstOne for $t {
+                const ONE: $t = $t::splat(1.0);
+            }
+
+            impl One for $t {
+                #[inline(always)]
+                fn one() -> $t { $t::splat(1.0) }
+            }
+
+            impl Min for $t {
+                #[inline(always)]
+                fn min(self, other: $t) -> $t { self.simd_min(other) }
+            }
+
+            impl Max for $t {
+                #[inline(always)]
+                fn max(self, other: $t) -> $t { self.simd_max(other) }
+            }
+
+            impl Signum for $t {
+                #[inline(always)]
+                fn signum(self) -> $t { SimdFloat::signum(self) }
+            }
+
+            impl Sqrt for $t {
+                #[inline(always)]
+                fn sqrt(self) -> $t { $t::from_array(self.to_array().map(<$s as Sqrt>::sqrt)) }
+            }
+
+            impl Powf for $t {
+                #[inline(always)]
+                fn powf(self, other: $t) -> $t {
+                    let (a, b) = (self.to_array(), other.to_array());
+                    $t::from_array(array::from_fn(|i| <$s as Powf>::powf(a[i], b[i])))
+                }
+            }
+
+            impl Trig for $t {
+                #[inline(always)]
+                fn sin(self) -> $t { $t::from_array(self.to_array().map(<$s as Trig>::sin)) }
+
+                #[inline(always)]
+                fn cos(self) -> $t { $t::from_array(self.to_array().map(<$s as Trig>::cos)) }
+
+                #[inline(always)]
+                fn tan(self) -> $t { $t::from_array(self.to_array().map(<$s as Trig>::tan)) }
+
+                #[inline(always)]
+                fn asin(self) -> $t { $t::from_array(self.to_array().map(<$s as Trig>::asin)) }
+
+                #[inline(always)]
+                fn acos(self) -> $t { $t::from_array(self.to_array().map(<$s as Trig>::acos)) }
+
+                #[inline(always)]
+                fn atan(self) -> $t { $t::from_array(self.to_array().map(<$s as Trig>::atan)) }
+
+                #[inline(always)]
+                fn atan2(self, other: $t) -> $t {
+                    let (a, b) = (self.to_array(), other.to_array());
+                    $t::from_array(array::from_fn(|i| <$s as Trig>::atan2(a[i], b[i])))
+                }
+
+                #[inline(always)]
+                fn sinh(self) -> $t { $t::from_array(self.to_array().map(<$s as Trig>::sinh)) }
+
+                #[inline(always)]
+                fn cosh(self) -> $t { $t::from_array(self.to_array().map(<$s as Trig>::cosh)) }
+
+                #[inline(always)]
+                fn tanh(self) -> $t { $t::from_array(self.to_array().map(<$s as Trig>::tanh)) }
+
+                #[inline(always)]
+                fn asinh(self) -> $t { $t::from_array(self.to_array().map(<$s as Trig>::asinh)) }
+
+                #[inline(always)]
+                fn acosh(self) -> $t { $t::from_array(self.to_array().map(<$s as Trig>::acosh)) }
+
+                #[inline(always)]
+                fn atanh(self) -> $t { $t::from_array(self.to_array().map(<$s as Trig>::atanh)) }
+            }
+        )*
+    };
+}
+
+simd_impls! {
+    f32x4: f32;
+    f32x8: f32;
+    f64x2: f64;
+    f64x4: f64;
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn update<T: Copy + Sqrt + Trig + Min + Max + core::ops::Mul<Output = T>>(x: T, v: T) -> T {
+        (x * v).sqrt().sin().max(v).min(x)
+    }
+
+    #[test]
+    fn test_lane_wise() {
+        let x = f32x4::from_array([1.0, 4.0, 9.0, 16.0]);
+        assert_eq!(<f32x4 as Sqrt>::sqrt(x).to_array(), [1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(<f64x2 as Min>::min(f64x2::from_array([1.0, 5.0]), f64x2::splat(2.0)).to_array(), [1.0, 2.0]);
+        assert_eq!(<f64x4 as Signum>::signum(f64x4::from_array([-3.0, 2.0, -0.5, 1.0])).to_array(), [-1.0, 1.0, -1.0, 1.0]);
+        let y = f32x8::splat(0.5);
+        let z = update(y, f32x8::splat(2.0));
+        assert_eq!(z.to_array(), [update(0.5_f32, 2.0); 8]);
+    }
+}
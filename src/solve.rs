@@ -0,0 +1,184 @@
+//! Root finding and polynomial equations.
+
+use core::ops::Deref;
+
+use Float;
+
+/// Finds a root of `f` in `[a, b]` by bisection.
+///
+/// Returns `None` if `f(a)` and `f(b)` have the same sign.
+/// Otherwise, stops when the bracket is narrower than `tolerance`
+/// or after `max_iter` halvings, and always converges for continuous `f`.
+pub fn bisect<T: Float, F: Fn(T) -> T>(f: F, a: T, b: T, tolerance: T, max_iter: usize) -> Option<T> {
+    let (mut a, mut b) = (a, b);
+    let (fa, fb) = (f(a), f(b));
+    if fa == T::zero() { return Some(a); }
+    if fb == T::zero() { return Some(b); }
+    if (fa < T::zero()) == (fb < T::zero()) { return None; }
+    let negative_at_a = fa < T::zero();
+    let half = T::from_f64(0.5);
+    for _ in 0..max_iter {
+        if (b - a).abs() <= tolerance { break; }
+        let mid = a + (b - a) * half;
+        let fm = f(mid);
+        if fm == T::zero() { return Some(mid); }
+        if (fm < T::zero()) == negative_at_a { a = mid; } else { b = mid; }
+    }
+    Some(a + (b - a) * half)
+}
+
+/// Finds a root of `f` with derivative `df` by Newton-Raphson iteration from `x0`.
+///
+/// Returns `None` if the derivative vanishes, an iterate is not finite,
+/// or no step is smaller than `tolerance` within `max_iter` iterations.
+pub fn newton<T, F, D>(f: F, df: D, x0: T, tolerance: T, max_iter: usize) -> Option<T>
+    where T: Float, F: Fn(T) -> T, D: Fn(T) -> T
+{
+    let mut x = x0;
+    for _ in 0..max_iter {
+        let d = df(x);
+        if d == T::zero() { return None; }
+        let step = f(x) / d;
+        x -= step;
+        if !x.is_finite() { return None; }
+        if step.abs() <= tolerance { return Some(x); }
+    }
+    None
+}
+
+/// The real roots of a polynomial, in ascending order.
+///
+/// Dereferences to a slice. Repeated roots are listed once.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Roots<T> {
+    roots: [T; 3],
+    len: usize,
+}
+
+impl<T: Float> Roots<T> {
+    fn new(roots: &[T]) -> Roots<T> {
+        let mut r = Roots { roots: [T::zero(); 3], len: 0 };
+        for &x in roots { r.push(x); }
+        r
+    }
+
+    /// Inserts a root, keeping the order and skipping duplicates.
+    fn push(&mut self, x: T) {
+        let mut i = self.len;
+        while i > 0 && self.roots[i - 1] > x { i -= 1; }
+        if i > 0 && self.roots[i - 1] == x { return; }
+        for j in (i..self.len).rev() { self.roots[j + 1] = self.roots[j]; }
+        self.roots[i] = x;
+        self.len += 1;
+    }
+}
+
+impl<T> Deref for Roots<T> {
+    type Target = [T];
+    fn deref(&self) -> &[T] { &self.roots[..self.len] }
+}
+
+/// Returns the real roots of `a * x^2 + b * x + c = 0`.
+///
+/// Falls back to the linear equation when `a` is zero,
+/// and returns no roots when every coefficient is zero.
+pub fn solve_quadratic<T: Float>(a: T, b: T, c: T) -> Roots<T> {
+    if a == T::zero() {
+        return if b == T::zero() { Roots::new(&[]) } else { Roots::new(&[-c / b]) };
+    }
+    let disc = b * b - T::from_f64(4.0) * a * c;
+    if disc < T::zero() { return Roots::new(&[]); }
+    if disc == T::zero() { return Roots::new(&[-b / (a + a)]); }
+    // Avoids the cancellation of `-b + sqrt(disc)` when `b` dominates.
+    let q = -(b + disc.sqrt().copysign(b)) / T::from_f64(2.0);
+    Roots::new(&[q / a, c / q])
+}
+
+/// Returns the real roots of `a * x^3 + b * x^2 + c * x + d = 0`.
+///
+/// Falls back to `solve_quadratic` when `a` is zero.
+/// Each root is refined with a Newton step.
+pub fn solve_cubic<T: Float>(a: T, b: T, c: T, d: T) -> Roots<T> {
+    if a == T::zero() { return solve_quadratic(b, c, d); }
+    let (two, three) = (T::from_f64(2.0), T::from_f64(3.0));
+    let (b, c, d) = (b / a, c / a, d / a);
+    // Substituting `x = t - b / 3` gives the depressed cubic `t^3 + p * t + q = 0`.
+    let shift = b / three;
+    let p = c - b * shift;
+    let q = two * shift * shift * shift - c * shift + d;
+    let half_q = q / two;
+    let third_p = p / three;
+    let disc = half_q * half_q + third_p * third_p * third_p;
+
+    let refine = |x: T| {
+        let fx = ((x + b) * x + c) * x + d;
+        let dfx = (three * x + two * b) * x + c;
+        if dfx == T::zero() { x } else { x - fx / dfx }
+    };
+    if disc > T::zero() {
+        let u = -(half_q.abs() + disc.sqrt()).cbrt().copysign(q);
+        let t = if u == T::zero() { u } else { u - third_p / u };
+        Roots::new(&[refine(t - shift)])
+    } else if disc == T::zero() {
+        if p == T::zero() { return Roots::new(&[-shift]); }
+        let t = three * q / p;
+        Roots::new(&[t - shift, -t / two - shift])
+    } else {
+        let r = two * (-third_p).sqrt();
+        let phi = (three * q / (two * p) * (-three / p).sqrt()).clamp(-T::one(), T::one()).acos() / three;
+        let step = T::tau() / three;
+        Roots::new(&[
+            refine(r * phi.cos() - shift),
+            refine(r * (phi - step).cos() - shift),
+            refine(r * (phi + step).cos() - shift),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bisect() {
+        let root = bisect(|x: f64| x * x - 2.0, 0.0, 2.0, 1e-12, 100).unwrap();
+        assert!((root - 2.0_f64.sqrt()).abs() < 1e-12);
+        assert_eq!(bisect(|x: f32| x - 1.0, 1.0, 5.0, 1e-6, 100), Some(1.0));
+        assert_eq!(bisect(|x: f32| x * x + 1.0, -1.0, 1.0, 1e-6, 100), None);
+    }
+
+    #[test]
+    fn test_newton() {
+        let root = newton(|x: f64| x * x - 2.0, |x| 2.0 * x, 1.0, 1e-14, 50).unwrap();
+        assert!((root - 2.0_f64.sqrt()).abs() < 1e-15);
+        assert_eq!(newton(|x: f64| x * x + 1.0, |x| 2.0 * x, 0.0, 1e-14, 50), None);
+        assert_eq!(newton(|x: f32| x * x + 1.0, |x| 2.0 * x, 0.5, 1e-6, 20), None);
+    }
+
+    #[test]
+    fn test_quadratic() {
+        assert_eq!(&*solve_quadratic(1.0_f64, -3.0, 2.0), &[1.0, 2.0]);
+        assert_eq!(&*solve_quadratic(1.0_f64, -2.0, 1.0), &[1.0]);
+        assert!(solve_quadratic(1.0_f32, 0.0, 1.0).is_empty());
+        assert_eq!(&*solve_quadratic(0.0_f32, 2.0, -1.0), &[0.5]);
+        assert!(solve_quadratic(0.0_f32, 0.0, 0.0).is_empty());
+        assert_eq!(&*solve_quadratic(1.0_f64, 0.0, -4.0), &[-2.0, 2.0]);
+        // The small root keeps full precision.
+        let roots = solve_quadratic(1.0_f64, -1e8, 1.0);
+        assert!((roots[0] - 1e-8).abs() < 1e-22);
+    }
+
+    #[test]
+    fn test_cubic() {
+        let roots = solve_cubic(1.0_f64, -6.0, 11.0, -6.0);
+        assert_eq!(roots.len(), 3);
+        for (r, e) in roots.iter().zip(&[1.0, 2.0, 3.0]) { assert!((r - e).abs() < 1e-12); }
+        assert_eq!(&*solve_cubic(1.0_f64, 0.0, -3.0, 2.0), &[-2.0, 1.0]);
+        assert_eq!(&*solve_cubic(2.0_f64, 0.0, 0.0, -16.0), &[2.0]);
+        assert_eq!(&*solve_cubic(1.0_f32, -3.0, 3.0, -1.0), &[1.0]);
+        assert_eq!(&*solve_cubic(0.0_f64, 1.0, -3.0, 2.0), &[1.0, 2.0]);
+        let roots = solve_cubic(1.0_f32, 1.0, 1.0, 1.0);
+        assert_eq!(roots.len(), 1);
+        assert!((roots[0] + 1.0).abs() < 1e-6);
+    }
+}
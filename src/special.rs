@@ -0,0 +1,229 @@
+//! Special functions.
+//!
+//! Evaluated in `f64` with the bundled pure Rust math routines for both float types,
+//! so the results are the same with `std`, `libm` and `deterministic`.
+
+#![allow(clippy::excessive_precision, clippy::unreadable_literal)]
+
+use libm::{exp, log, pow, round, sin};
+
+/// The error and gamma functions.
+///
+/// The relative error is below `4e-15` for `erf` and `erfc`.
+/// For `gamma` and `ln_gamma` it grows with the magnitude of the argument, up to `2e-13`,
+/// and `ln_gamma` has an absolute error below that around its zeros at 1 and 2.
+/// The `f32` results are rounded from these.
+pub trait Special {
+    /// Returns the error function, `2 / sqrt(π)` times the integral of `e^(-t^2)` from 0 to self.
+    fn erf(self) -> Self;
+    /// Returns the complementary error function, `1 - erf(self)`,
+    /// without the cancellation for large arguments.
+    fn erfc(self) -> Self;
+    /// Returns the gamma function, which extends the factorial with `gamma(n + 1) = n!`.
+    ///
+    /// Returns NaN at negative integers and an infinity with the sign of zero at zero.
+    fn gamma(self) -> Self;
+    /// Returns the natural logarithm of the absolute value of the gamma function.
+    ///
+    /// Returns infinity at zero and the negative integers.
+    fn ln_gamma(self) -> Self;
+}
+
+const FRAC_2_SQRT_PI: f64 = ::core::f64::consts::FRAC_2_SQRT_PI;
+const PI: f64 = ::core::f64::consts::PI;
+const SQRT_2PI: f64 = 2.5066282746310002;
+
+/// Returns `e^(-x^2)` without the rounding error of squaring `x`.
+fn exp_neg_sqr(x: f64) -> f64 {
+    // `hi` has 21 significant bits, so `hi * hi` is exact.
+    let hi = f64::from_bits(x.to_bits() & 0xffff_ffff_0000_0000);
+    exp(-hi * hi) * exp(-(x - hi) * (x + hi))
+}
+
+/// Evaluates `erf` for `|x| < 2` with a series of positive terms.
+fn erf_series(x: f64) -> f64 {
+    let x2 = 2.0 * x * x;
+    let (mut term, mut sum, mut n) = (x, x, 1.0);
+    while term.abs() > sum.abs() * 1e-17 {
+        term *= x2 / (2.0 * n + 1.0);
+        sum += term;
+        n += 1.0;
+    }
+    FRAC_2_SQRT_PI * exp_neg_sqr(x) * sum
+}
+
+/// Evaluates `erfc` for `x >= 1` with a continued fraction.
+fn erfc_fraction(x: f64) -> f64 {
+    if x > 27.3 { return 0.0; }
+    // Modified Lentz evaluation of `1 / (x + (1/2) / (x + 1 / (x + (3/2) / (x + ...))))`.
+    let tiny = 1e-300;
+    let (mut f, mut c, mut d) = (x, x, 0.0);
+    let mut n = 1.0;
+    loop {
+        let a = n / 2.0;
+        d = x + a * d;
+        if d == 0.0 { d = tiny; }
+        c = x + a / c;
+        if c == 0.0 { c = tiny; }
+        d = 1.0 / d;
+        let delta = c * d;
+        f *= delta;
+        if (delta - 1.0).abs() < 1e-16 { break; }
+        n += 1.0;
+    }
+    FRAC_2_SQRT_PI / 2.0 * exp_neg_sqr(x) / f
+}
+
+fn erf(x: f64) -> f64 {
+    if x.is_nan() { return x; }
+    if x.abs() < 2.0 { erf_series(x) } else { (1.0 - erfc_fraction(x.abs())).copysign(x) }
+}
+
+fn erfc(x: f64) -> f64 {
+    if x.is_nan() { return x; }
+    if x < 1.0 { 1.0 - erf(x) } else { erfc_fraction(x) }
+}
+
+const LANCZOS_G: f64 = 7.0;
+const LANCZOS: [f64; 9] = [
+    0.99999999999980993,
+    676.5203681218851,
+    -1259.1392167224028,
+    771.32342877765313,
+    -176.61502916214059,
+    12.507343278686905,
+    -0.13857109526572012,
+    9.9843695780195716e-6,
+    1.5056327351493116e-7,
+];
+
+/// Returns the Lanczos series for `gamma(x + 1)`.
+fn lanczos_sum(x: f64) -> f64 {
+    LANCZOS[1..].iter().enumerate().fold(LANCZOS[0], |sum, (i, &c)| sum + c / (x + i as f64 + 1.0))
+}
+
+/// Returns `sin(π x)`, exactly zero at the integers.
+fn sin_pi(x: f64) -> f64 {
+    let r = x - 2.0 * round(x / 2.0);
+    if r == 0.0 || r.abs() == 1.0 { return 0.0; }
+    // Reflect into `[-1/2, 1/2]`, exactly, since `PI * r` near `±π` loses the distance to it.
+    let r = if r.abs() > 0.5 { 1.0_f64.copysign(r) - r } else { r };
+    sin(PI * r)
+}
+
+fn gamma(x: f64) -> f64 {
+    if x.is_nan() || x == f64::INFINITY { return x; }
+    if x == 0.0 { return f64::INFINITY.copysign(x); }
+    if x < 0.5 {
+        let s = sin_pi(x);
+        if s == 0.0 { return f64::NAN; }
+        return PI / (s * gamma(1.0 - x));
+    }
+    if x > 171.7 { return f64::INFINITY; }
+    let z = x - 1.0;
+    let t = z + LANCZOS_G + 0.5;
+    // Split in two, since `t^(z + 0.5)` alone overflows before `e^(-t)` scales it back.
+    let p = pow(t, (z + 0.5) / 2.0);
+    SQRT_2PI * p * (exp(-t) * p) * lanczos_sum(z)
+}
+
+fn ln_gamma(x: f64) -> f64 {
+    if x.is_nan() || x.is_infinite() { return x.abs(); }
+    if x < 0.5 {
+        let s = sin_pi(x);
+        if s == 0.0 { return f64::INFINITY; }
+        return log(PI / s.abs()) - ln_gamma(1.0 - x);
+    }
+    // Exact zeros, where the formula below only cancels approximately.
+    if x == 1.0 || x == 2.0 { return 0.0; }
+    let z = x - 1.0;
+    let t = z + LANCZOS_G + 0.5;
+    log(SQRT_2PI) + (z + 0.5) * log(t) - t + log(lanczos_sum(z))
+}
+
+impl Special for f64 {
+    #[inline(always)]
+    fn erf(self) -> f64 { erf(self) }
+
+    #[inline(always)]
+    fn erfc(self) -> f64 { erfc(self) }
+
+    #[inline(always)]
+    fn gamma(self) -> f64 { gamma(self) }
+
+    #[inline(always)]
+    fn ln_gamma(self) -> f64 { ln_gamma(self) }
+}
+
+impl Special for f32 {
+    #[inline(always)]
+    fn erf(self) -> f32 { erf(self as f64) as f32 }
+
+    #[inline(always)]
+    fn erfc(self) -> f32 { erfc(self as f64) as f32 }
+
+    #[inline(always)]
+    fn gamma(self) -> f32 { gamma(self as f64) as f32 }
+
+    #[inline(always)]
+    fn ln_gamma(self) -> f32 { ln_gamma(self as f64) as f32 }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn close(a: f64, b: f64, tolerance: f64) -> bool { (a - b).abs() <= tolerance * b.abs() }
+
+    #[test]
+    fn test_erf() {
+        // Reference values from the `math` module of CPython.
+        assert_eq!(Special::erf(0.0_f64), 0.0);
+        assert!(close(Special::erf(0.5_f64), 0.5204998778130465, 4e-15));
+        assert!(close(Special::erf(-1.5_f64), -0.9661051464753108, 4e-15));
+        assert!(close(Special::erf(3.0_f64), 0.9999779095030014, 4e-15));
+        assert_eq!(Special::erf(f64::INFINITY), 1.0);
+        assert!(close(Special::erfc(0.1_f64), 0.8875370839817152, 4e-15));
+        assert!(close(Special::erfc(1.0_f64), 0.15729920705028513, 4e-15));
+        assert!(close(Special::erfc(5.0_f64), 1.5374597944280351e-12, 4e-15));
+        assert!(close(Special::erfc(26.0_f64), 5.663192408856143e-296, 4e-15));
+        assert!(close(Special::erfc(-2.0_f64), 1.9953222650189528, 4e-15));
+        assert_eq!(Special::erfc(30.0_f64), 0.0);
+        assert_eq!(Special::erf(0.5_f32), 0.5204999);
+        assert!(Special::erfc(f32::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_gamma() {
+        let mut factorial = 1.0;
+        for n in 1..20 {
+            assert!(close(Special::gamma(n as f64), factorial, 1e-14), "{}", n);
+            factorial *= n as f64;
+        }
+        assert!(close(Special::gamma(0.5_f64), PI.sqrt(), 1e-15));
+        assert!(close(Special::gamma(-1.5_f64), 4.0 / 3.0 * PI.sqrt(), 1e-15));
+        assert!(close(Special::gamma(170.5_f64), 5.56209241456e305, 2e-13));
+        assert_eq!(Special::gamma(172.0_f64), f64::INFINITY);
+        assert_eq!(Special::gamma(-0.0_f64), f64::NEG_INFINITY);
+        assert!(Special::gamma(-3.0_f64).is_nan());
+        // Next to odd negative integers, where `sin(π x)` is evaluated near `±π`.
+        assert!(close(Special::gamma(-0.999999999_f64), -1000000028.7047167, 2e-13));
+        assert!(close(Special::gamma(-2.999999999_f64), -166666653.085959, 2e-13));
+        assert!(close(Special::gamma(-5.000000001_f64), 8333332.6296126535, 2e-13));
+        assert_eq!(Special::gamma(5.0_f32), 24.0);
+    }
+
+    #[test]
+    fn test_ln_gamma() {
+        assert_eq!(Special::ln_gamma(1.0_f64), 0.0);
+        assert_eq!(Special::ln_gamma(2.0_f64), 0.0);
+        assert!(close(Special::ln_gamma(0.5_f64), 0.5723649429247004, 1e-14));
+        assert!(close(Special::ln_gamma(-2.5_f64), -0.05624371649767457, 1e-13));
+        assert!(close(Special::ln_gamma(1e10_f64), 220258509288.81058, 1e-14));
+        assert!(close(Special::ln_gamma(1e300_f64), 6.897755278982137e302, 1e-14));
+        assert!(close(Special::ln_gamma(-1.0_f64 + 1e-8), 18.42068074315545, 1e-14));
+        assert!(close(Special::ln_gamma(-3.0_f64 - 1e-8), 16.628921268240607, 1e-14));
+        assert_eq!(Special::ln_gamma(-4.0_f64), f64::INFINITY);
+        assert_eq!(Special::ln_gamma(0.0_f32), f32::INFINITY);
+    }
+}
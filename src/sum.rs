@@ -0,0 +1,118 @@
+//! Compensated summation.
+
+use core::iter::FromIterator;
+
+use Float;
+
+/// Accumulates a sum with Kahan compensated summation.
+///
+/// The rounding error of each addition is tracked and fed back into the next,
+/// so the error stays bounded independent of the number of terms.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct KahanSum<T> {
+    sum: T,
+    compensation: T,
+}
+
+impl<T: Float> KahanSum<T> {
+    /// Returns an empty sum.
+    #[inline(always)]
+    pub fn new() -> KahanSum<T> {
+        KahanSum { sum: T::zero(), compensation: T::zero() }
+    }
+
+    /// Adds a value to the sum.
+    #[inline(always)]
+    pub fn add(&mut self, value: T) {
+        let y = value - self.compensation;
+        let t = self.sum + y;
+        self.compensation = (t - self.sum) - y;
+        self.sum = t;
+    }
+
+    /// Returns the sum.
+    #[inline(always)]
+    pub fn sum(&self) -> T { self.sum }
+}
+
+/// Accumulates a sum with Neumaier's improved Kahan summation.
+///
+/// Unlike `KahanSum`, this stays accurate when a term is larger
+/// in magnitude than the running sum, at the cost of a branch per term.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct NeumaierSum<T> {
+    sum: T,
+    compensation: T,
+}
+
+impl<T: Float> NeumaierSum<T> {
+    /// Returns an empty sum.
+    #[inline(always)]
+    pub fn new() -> NeumaierSum<T> {
+        NeumaierSum { sum: T::zero(), compensation: T::zero() }
+    }
+
+    /// Adds a value to the sum.
+    #[inline(always)]
+    pub fn add(&mut self, value: T) {
+        let t = self.sum + value;
+        if self.sum.abs() >= value.abs() {
+            self.compensation += (self.sum - t) + value;
+        } else {
+            self.compensation += (value - t) + self.sum;
+        }
+        self.sum = t;
+    }
+
+    /// Returns the sum.
+    #[inline(always)]
+    pub fn sum(&self) -> T { self.sum + self.compensation }
+}
+
+macro_rules! sum_iter_impls {
+    ($($sum:ident),*) => {
+        $(
+            impl<T: Float> Extend<T> for $sum<T> {
+                fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+                    for value in iter { self.add(value); }
+                }
+            }
+
+            impl<T: Float> FromIterator<T> for $sum<T> {
+                fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> $sum<T> {
+                    let mut sum = $sum::new();
+                    sum.extend(iter);
+                    sum
+                }
+            }
+        )*
+    };
+}
+
+sum_iter_impls!(KahanSum, NeumaierSum);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_kahan() {
+        let naive = (0..100_000).fold(0.0_f32, |acc, _| acc + 0.001);
+        let kahan: KahanSum<f32> = (0..100_000).map(|_| 0.001).collect();
+        assert!((naive - 100.0).abs() > 1e-2);
+        assert!((kahan.sum() - 100.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_neumaier() {
+        let values = [1.0_f64, 1e100, 1.0, -1e100];
+        let kahan: KahanSum<f64> = values.iter().cloned().collect();
+        let mut neumaier = NeumaierSum::new();
+        neumaier.extend(values.iter().cloned());
+        assert_eq!(kahan.sum(), 0.0);
+        assert_eq!(neumaier.sum(), 2.0);
+        neumaier.add(0.5);
+        assert_eq!(neumaier.sum(), 2.5);
+        assert_eq!(NeumaierSum::<f32>::default().sum(), 0.0);
+    }
+}
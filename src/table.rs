@@ -0,0 +1,173 @@
+//! Lookup-table trigonometry.
+//!
+//! Tables trade accuracy for a predictable cost with no calls into a math library,
+//! which pays off on targets without a fast FPU or libm.
+//! On desktop CPUs the native `sin` is usually about as fast,
+//! and a large table competes for cache, so measure before switching.
+
+use {Float, Lerp, Trig};
+
+/// A table of `N` sines over one full turn, interpolated linearly.
+///
+/// The absolute error is below `(τ / N)^2 / 8`, about `7.5e-5` for 256 entries.
+/// Like `FastApprox`, the angle is wrapped first, which loses precision for large inputs.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SineTable<T, const N: usize> {
+    values: [T; N],
+}
+
+impl<T: Float, const N: usize> SineTable<T, N> {
+    /// Computes the table.
+    ///
+    /// Panics if `N` is zero.
+    pub fn new() -> SineTable<T, N> {
+        assert!(N > 0, "a sine table needs at least one entry");
+        let mut values = [T::zero(); N];
+        let step = T::tau() / T::from_usize(N);
+        for (i, v) in values.iter_mut().enumerate() {
+            *v = (T::from_usize(i) * step).sin();
+        }
+        SineTable { values }
+    }
+
+    /// Looks up the sine at `t` entries into the table.
+    #[inline(always)]
+    fn lookup(&self, t: T) -> T {
+        let t = t.rem_euclid(T::from_usize(N));
+        let i = t.floor();
+        // The remainder may round up to `N` itself.
+        let index = i.to_usize() % N;
+        self.values[index].lerp(self.values[(index + 1) % N], t - i)
+    }
+
+    /// Returns the approximate sine of `x`.
+    #[inline(always)]
+    pub fn sin(&self, x: T) -> T { self.lookup(x * T::from_usize(N) / T::tau()) }
+
+    /// Returns the approximate cosine of `x`.
+    #[inline(always)]
+    pub fn cos(&self, x: T) -> T {
+        self.lookup(x * T::from_usize(N) / T::tau() + T::from_usize(N) / T::from_f64(4.0))
+    }
+
+    /// Returns the approximate tangent of `x`.
+    #[inline(always)]
+    pub fn tan(&self, x: T) -> T { self.sin(x) / self.cos(x) }
+
+    /// Returns the approximate sine and cosine of `x`.
+    #[inline(always)]
+    pub fn sin_cos(&self, x: T) -> (T, T) { (self.sin(x), self.cos(x)) }
+}
+
+impl<T: Float, const N: usize> Default for SineTable<T, N> {
+    fn default() -> SineTable<T, N> { SineTable::new() }
+}
+
+/// A value whose `Trig` impl looks up `sin`, `cos` and `tan` in a `SineTable`.
+///
+/// Lets code generic over `Trig` run on the table.
+/// The inverse and hyperbolic functions use the `Trig` impl of `T`.
+#[derive(Clone, Copy, Debug)]
+pub struct TableTrig<'a, T, const N: usize> {
+    /// The wrapped value.
+    pub value: T,
+    table: &'a SineTable<T, N>,
+}
+
+impl<'a, T: Float, const N: usize> TableTrig<'a, T, N> {
+    /// Wraps a value, to be evaluated with the given table.
+    #[inline(always)]
+    pub fn new(value: T, table: &'a SineTable<T, N>) -> TableTrig<'a, T, N> {
+        TableTrig { value, table }
+    }
+
+    #[inline(always)]
+    fn map<F: Fn(T) -> T>(self, f: F) -> TableTrig<'a, T, N> { TableTrig::new(f(self.value), self.table) }
+}
+
+impl<'a, T: Float, const N: usize> Trig for TableTrig<'a, T, N> {
+    #[inline(always)]
+    fn sin(self) -> Self { self.map(|x| self.table.sin(x)) }
+
+    #[inline(always)]
+    fn cos(self) -> Self { self.map(|x| self.table.cos(x)) }
+
+    #[inline(always)]
+    fn tan(self) -> Self { self.map(|x| self.table.tan(x)) }
+
+    #[inline(always)]
+    fn asin(self) -> Self { self.map(T::asin) }
+
+    #[inline(always)]
+    fn acos(self) -> Self { self.map(T::acos) }
+
+    #[inline(always)]
+    fn atan(self) -> Self { self.map(T::atan) }
+
+    #[inline(always)]
+    fn atan2(self, other: Self) -> Self { self.map(|y| y.atan2(other.value)) }
+
+    #[inline(always)]
+    fn sinh(self) -> Self { self.map(T::sinh) }
+
+    #[inline(always)]
+    fn cosh(self) -> Self { self.map(T::cosh) }
+
+    #[inline(always)]
+    fn tanh(self) -> Self { self.map(T::tanh) }
+
+    #[inline(always)]
+    fn asinh(self) -> Self { self.map(T::asinh) }
+
+    #[inline(always)]
+    fn acosh(self) -> Self { self.map(T::acosh) }
+
+    #[inline(always)]
+    fn atanh(self) -> Self { self.map(T::atanh) }
+
+    #[inline(always)]
+    fn sin_cos(self) -> (Self, Self) {
+        let (s, c) = self.table.sin_cos(self.value);
+        (TableTrig::new(s, self.table), TableTrig::new(c, self.table))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use Consts;
+
+    fn max_error<const N: usize>(f: fn(&SineTable<f64, N>, f64) -> f64, g: fn(f64) -> f64) -> f64 {
+        let table = SineTable::<f64, N>::new();
+        (-2000..2000).map(|i| i as f64 * 0.00517).map(|x| (f(&table, x) - g(x)).abs()).fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn test_accuracy() {
+        let bound = |n: f64| {
+            let h = <f64 as Consts>::tau() / n;
+            h * h / 8.0 + 1e-15
+        };
+        assert!(max_error::<256>(SineTable::sin, <f64 as Trig>::sin) < bound(256.0));
+        assert!(max_error::<256>(SineTable::cos, <f64 as Trig>::cos) < bound(256.0));
+        assert!(max_error::<1000>(SineTable::sin, <f64 as Trig>::sin) < bound(1000.0));
+        assert!(max_error::<1000>(SineTable::cos, <f64 as Trig>::cos) < bound(1000.0));
+        let table = SineTable::<f32, 64>::new();
+        assert_eq!(table.sin(0.0), 0.0);
+        assert_eq!(table.cos(0.0), 1.0);
+        assert!(table.sin(-1e-30).abs() < 1e-6);
+        assert!(table.sin(f32::NAN).is_nan());
+    }
+
+    #[test]
+    fn test_wrapper() {
+        fn wave<T: Trig + Copy>(x: T) -> (T, T) { (x.sin(), x.cos().asin()) }
+
+        let table = SineTable::<f64, 512>::new();
+        let (s, a) = wave(TableTrig::new(1.0, &table));
+        assert_eq!(s.value, table.sin(1.0));
+        assert_eq!(a.value, Trig::asin(table.cos(1.0)));
+        assert_eq!(TableTrig::new(1.0, &table).atan2(TableTrig::new(1.0, &table)).value,
+                   <f64 as Consts>::pi() / 4.0);
+    }
+}